@@ -0,0 +1,56 @@
+use serde_json::json;
+
+/// Service name registered with Consul.
+const SERVICE_NAME: &str = "test-runner-mcp";
+
+/// Register this server with the local Consul agent so the SSE endpoint can be
+/// discovered and health-checked. Best-effort: errors are logged, not fatal.
+pub async fn register(consul_addr: &str, address: &str, port: u16) -> String {
+    let id = format!("{}-{}-{}", SERVICE_NAME, address, port);
+    let body = json!({
+        "ID": id,
+        "Name": SERVICE_NAME,
+        "Address": address,
+        "Port": port,
+        "Check": {
+            "HTTP": format!("http://{}:{}/sse", address, port),
+            "Interval": "10s",
+        },
+    });
+
+    let url = format!("{}/v1/agent/service/register", consul_addr.trim_end_matches('/'));
+    let client = reqwest::Client::new();
+    match client.put(&url).json(&body).send().await {
+        Ok(resp) if resp.status().is_success() => {
+            tracing::info!(service = %id, "registered with Consul");
+        }
+        Ok(resp) => {
+            tracing::warn!(status = %resp.status(), "Consul registration returned non-success");
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "failed to register with Consul");
+        }
+    }
+    id
+}
+
+/// Deregister the service from the local Consul agent on shutdown.
+pub async fn deregister(consul_addr: &str, id: &str) {
+    let url = format!(
+        "{}/v1/agent/service/deregister/{}",
+        consul_addr.trim_end_matches('/'),
+        id
+    );
+    let client = reqwest::Client::new();
+    match client.put(&url).send().await {
+        Ok(resp) if resp.status().is_success() => {
+            tracing::info!(service = %id, "deregistered from Consul");
+        }
+        Ok(resp) => {
+            tracing::warn!(status = %resp.status(), "Consul deregistration returned non-success");
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "failed to deregister from Consul");
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use serde::Serialize;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::process::Command;
+use tokio::sync::mpsc;
+
+use crate::test_runner::TestRunner;
+
+/// Outcome of a single streamed test result.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", content = "message", rename_all = "snake_case")]
+pub enum Outcome {
+    Ok,
+    Failed(String),
+    Pending,
+}
+
+/// Incremental event emitted as a run progresses, forwarded onto the SSE
+/// connection so clients see live feedback instead of a single final blob.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TestEvent {
+    Plan { total: usize, filtered: usize },
+    Wait { name: String },
+    Result {
+        name: String,
+        duration_ms: u64,
+        outcome: Outcome,
+    },
+    Summary {
+        passes: u32,
+        failures: u32,
+        pending: u32,
+        duration: u32,
+    },
+}
+
+/// Pull an inline `(123ms)`/`(1.2s)` duration off the end of a reporter line.
+fn parse_duration_ms(line: &str) -> u64 {
+    let Some(open) = line.rfind('(') else {
+        return 0;
+    };
+    let inner = line[open + 1..].trim_end_matches(')').trim();
+    if let Some(ms) = inner.strip_suffix("ms") {
+        ms.trim().parse().unwrap_or(0)
+    } else if let Some(s) = inner.strip_suffix('s') {
+        s.trim().parse::<f64>().map(|v| (v * 1000.0) as u64).unwrap_or(0)
+    } else {
+        0
+    }
+}
+
+/// Raw captured output of a streamed run, returned once every incremental
+/// event has been emitted. The caller extracts/parses the reporter JSON itself
+/// so the existing fallbacks (and raw stdout/stderr diagnostics) are preserved.
+pub struct StreamedOutput {
+    pub stdout: String,
+    pub stderr: String,
+    pub exit_code: i32,
+}
+
+/// Spawn the Cypress run, stream incremental events over `tx` as stdout lines
+/// arrive, and return the full captured output for authoritative parsing.
+///
+/// Lines are read through a [`BufReader`] so the caller sees per-test progress;
+/// the complete stdout is accumulated alongside stderr and the real exit code
+/// so the caller keeps every diagnostic the non-streaming path had.
+pub async fn stream_cypress(
+    command: &str,
+    file: &str,
+    tx: mpsc::Sender<TestEvent>,
+) -> Result<StreamedOutput, String> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} {}", command, file))
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to spawn Cypress: {}", e))?;
+
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| "Failed to capture Cypress stdout".to_string())?;
+
+    // Drain stderr concurrently so a chatty process can't deadlock on a full pipe.
+    let stderr = child.stderr.take();
+    let stderr_task = tokio::spawn(async move {
+        let mut buf = String::new();
+        if let Some(mut stderr) = stderr {
+            let _ = stderr.read_to_string(&mut buf).await;
+        }
+        buf
+    });
+
+    let mut reader = BufReader::new(stdout).lines();
+    let mut buffer = String::new();
+
+    while let Some(line) = reader
+        .next_line()
+        .await
+        .map_err(|e| format!("Failed to read Cypress output: {}", e))?
+    {
+        buffer.push_str(&line);
+        buffer.push('\n');
+
+        let trimmed = line.trim();
+        // Mocha spec-reporter glyphs: ✓/√ pass, ✗/✖ fail, - pending.
+        if let Some(name) = trimmed.strip_prefix('✓').or_else(|| trimmed.strip_prefix('√')) {
+            let _ = tx
+                .send(TestEvent::Result {
+                    name: name.trim().to_string(),
+                    duration_ms: parse_duration_ms(trimmed),
+                    outcome: Outcome::Ok,
+                })
+                .await;
+        } else if let Some(name) = trimmed.strip_prefix('-') {
+            let _ = tx
+                .send(TestEvent::Result {
+                    name: name.trim().to_string(),
+                    duration_ms: 0,
+                    outcome: Outcome::Pending,
+                })
+                .await;
+        } else if trimmed.starts_with('✗') || trimmed.starts_with("✖") {
+            let name = trimmed.trim_start_matches(['✗', '✖']).trim().to_string();
+            let _ = tx
+                .send(TestEvent::Result {
+                    name,
+                    duration_ms: parse_duration_ms(trimmed),
+                    outcome: Outcome::Failed(trimmed.to_string()),
+                })
+                .await;
+        }
+    }
+
+    let status = child
+        .wait()
+        .await
+        .map_err(|e| format!("Cypress process error: {}", e))?;
+    let stderr = stderr_task.await.unwrap_or_default();
+
+    // Emit a closing summary when the reporter JSON is parseable; best-effort so
+    // a non-test failure (which the caller surfaces from the raw output) does
+    // not swallow the incremental stream.
+    if let Ok(json_str) = TestRunner::extract_json_from_cypress_output(&buffer) {
+        if let Ok(results) = TestRunner::parse_cypress_results(&json_str) {
+            let _ = tx
+                .send(TestEvent::Summary {
+                    passes: results.stats.passes,
+                    failures: results.stats.failures,
+                    pending: results.stats.pending,
+                    duration: results.stats.duration,
+                })
+                .await;
+        }
+    }
+
+    Ok(StreamedOutput {
+        stdout: buffer,
+        stderr,
+        exit_code: status.code().unwrap_or(-1),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration_ms() {
+        assert_eq!(parse_duration_ms("✓ logs in (123ms)"), 123);
+        assert_eq!(parse_duration_ms("✓ slow thing (1.5s)"), 1500);
+        assert_eq!(parse_duration_ms("✓ no duration"), 0);
+    }
+
+    #[test]
+    fn test_event_serialization() {
+        let ev = TestEvent::Result {
+            name: "logs in".to_string(),
+            duration_ms: 12,
+            outcome: Outcome::Failed("boom".to_string()),
+        };
+        let json = serde_json::to_string(&ev).unwrap();
+        assert!(json.contains("\"type\":\"result\""));
+        assert!(json.contains("\"status\":\"failed\""));
+    }
+}
@@ -0,0 +1,56 @@
+use tokio::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+/// Local Git state captured at run time, mirroring how CI dashboards associate
+/// a test run with the exact commit that produced it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitContext {
+    pub branch: String,
+    pub commit_short: String,
+    pub commit: String,
+    pub author: String,
+    pub subject: String,
+    pub dirty: bool,
+}
+
+/// Run a `git` subcommand in `dir`, returning its trimmed stdout on success.
+async fn git(dir: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Collect Git context for `dir`, returning `None` when the directory is not a
+/// repository or `git` is unavailable.
+///
+/// Invariant: this never errors — callers attach the result as an optional
+/// field rather than failing the run.
+pub async fn collect(dir: &str) -> Option<GitContext> {
+    let branch = git(dir, &["rev-parse", "--abbrev-ref", "HEAD"]).await?;
+    let commit = git(dir, &["rev-parse", "HEAD"]).await?;
+    let commit_short = git(dir, &["rev-parse", "--short", "HEAD"]).await?;
+    let author = git(dir, &["log", "-1", "--pretty=%an"]).await?;
+    let subject = git(dir, &["log", "-1", "--pretty=%s"]).await?;
+    let dirty = git(dir, &["status", "--porcelain"])
+        .await
+        .map(|s| !s.is_empty())
+        .unwrap_or(false);
+
+    Some(GitContext {
+        branch,
+        commit_short,
+        commit,
+        author,
+        subject,
+        dirty,
+    })
+}
@@ -1,5 +1,6 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use rmcp::transport::sse_server::{SseServer, SseServerConfig};
+use rmcp::{transport::stdio, ServiceExt};
 use std::net::SocketAddr;
 use tracing_subscriber::{
     layer::SubscriberExt,
@@ -7,24 +8,71 @@ use tracing_subscriber::{
     {self},
 };
 
+mod consul;
+mod events;
+mod git;
+mod metrics;
+mod parsers;
+mod sonar;
 mod test_runner;
+mod watcher;
+mod webhook;
 use crate::test_runner::TestRunner;
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum Transport {
+    /// Serve over HTTP with Server-Sent Events.
+    Sse,
+    /// Serve over stdin/stdout for MCP hosts that launch the server as a child.
+    Stdio,
+}
+
 #[derive(Parser, Debug)]
 #[command(name = "test-runner-mcp")]
 #[command(about = "Test runner MCP server over HTTP with SSE")]
 struct Cli {
     #[arg(short = 'H', long, default_value = "127.0.0.1")]
     hostname: String,
-    
+
     #[arg(short, long, default_value = "30301")]
     port: u16,
+
+    #[arg(short, long, value_enum, default_value_t = Transport::Sse)]
+    transport: Transport,
+
+    /// Local Consul agent address (e.g. http://127.0.0.1:8500). When set, the
+    /// server registers for discovery/health-checking and deregisters on exit.
+    #[arg(long)]
+    consul_addr: Option<String>,
+
+    /// Incoming-webhook URL (Slack/Mattermost-style). When set, every parsed
+    /// Cypress run posts a summary to this endpoint.
+    #[arg(long, env = "WEBHOOK_URL")]
+    webhook_url: Option<String>,
+
+    /// Author/label shown on the webhook message.
+    #[arg(long, env = "WEBHOOK_LABEL")]
+    webhook_label: Option<String>,
+
+    /// Only post to the webhook when a run contains failures.
+    #[arg(long, env = "WEBHOOK_ON_FAILURE_ONLY")]
+    webhook_on_failure_only: bool,
+}
+
+impl Cli {
+    /// Build a webhook config from the CLI flags, if a URL was supplied.
+    fn webhook(&self) -> Option<webhook::WebhookConfig> {
+        self.webhook_url.clone().map(|url| webhook::WebhookConfig {
+            url,
+            label: self.webhook_label.clone(),
+            on_failure_only: self.webhook_on_failure_only,
+        })
+    }
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
-    let bind_address: SocketAddr = format!("{}:{}", cli.hostname, cli.port).parse()?;
     tracing_subscriber::registry()
         .with(
             tracing_subscriber::EnvFilter::try_from_default_env()
@@ -33,6 +81,23 @@ async fn main() -> anyhow::Result<()> {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    // stdio transport: no listener, no port — serve the service directly over
+    // stdin/stdout for MCP hosts that launch the server as a child process.
+    if cli.transport == Transport::Stdio {
+        tracing::info!("Starting Test Runner MCP server over stdio");
+        let mut service = TestRunner::new(
+            "bundle exec rspec".to_string(),
+            "npx cypress run --spec".to_string(),
+        );
+        if let Some(webhook) = cli.webhook() {
+            service = service.with_webhook(webhook);
+        }
+        let service = service.serve(stdio()).await?;
+        service.waiting().await?;
+        return Ok(());
+    }
+
+    let bind_address: SocketAddr = format!("{}:{}", cli.hostname, cli.port).parse()?;
     tracing::info!("Starting Docker Test Runner MCP server on {}", bind_address);
 
     let config = SseServerConfig {
@@ -45,7 +110,8 @@ async fn main() -> anyhow::Result<()> {
 
     let (sse_server, router) = SseServer::new(config);
 
-    // Do something with the router, e.g., add routes or middleware
+    // Expose a Prometheus metrics endpoint alongside the SSE/message routes.
+    let router = router.route("/metrics", axum::routing::get(metrics::render_handler));
 
     let listener = tokio::net::TcpListener::bind(sse_server.config.bind).await?;
 
@@ -62,7 +128,25 @@ async fn main() -> anyhow::Result<()> {
         }
     });
 
-    let ct = sse_server.with_service(TestRunner::new);
+    let webhook = cli.webhook();
+    let ct = sse_server.with_service(move || {
+        let mut runner = TestRunner::new(
+            "bundle exec rspec".to_string(),
+            "npx cypress run --spec".to_string(),
+        );
+        if let Some(webhook) = webhook.clone() {
+            runner = runner.with_webhook(webhook);
+        }
+        runner
+    });
+
+    // Optional Consul registration for service discovery and health checking.
+    let consul_id = match &cli.consul_addr {
+        Some(addr) => {
+            Some((addr.clone(), consul::register(addr, &cli.hostname, cli.port).await))
+        }
+        None => None,
+    };
 
     tracing::info!("Test Runner MCP server is running!");
     tracing::info!("SSE endpoint: http://{}/sse", bind_address);
@@ -70,6 +154,9 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!("Press Ctrl+C to stop");
 
     tokio::signal::ctrl_c().await?;
+    if let Some((addr, id)) = &consul_id {
+        consul::deregister(addr, id).await;
+    }
     ct.cancel();
     Ok(())
 }
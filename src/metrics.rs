@@ -0,0 +1,153 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Upper bounds (in milliseconds) for the per-run duration histogram buckets.
+const DURATION_BUCKETS_MS: [u64; 7] = [100, 500, 1_000, 5_000, 10_000, 30_000, 60_000];
+
+/// Process-wide test-run metrics, rendered in Prometheus text format at
+/// `/metrics`. Backed by lock-free atomics so the hot path stays cheap.
+pub struct Metrics {
+    runs_total: AtomicU64,
+    tests_passed: AtomicU64,
+    tests_failed: AtomicU64,
+    tests_pending: AtomicU64,
+    running: AtomicU64,
+    duration_buckets: [AtomicU64; DURATION_BUCKETS_MS.len()],
+    duration_inf: AtomicU64,
+    duration_sum_ms: AtomicU64,
+    duration_count: AtomicU64,
+}
+
+impl Metrics {
+    const fn new() -> Self {
+        // `AtomicU64::new(0)` is const, so the array can be built inline.
+        Metrics {
+            runs_total: AtomicU64::new(0),
+            tests_passed: AtomicU64::new(0),
+            tests_failed: AtomicU64::new(0),
+            tests_pending: AtomicU64::new(0),
+            running: AtomicU64::new(0),
+            duration_buckets: [
+                AtomicU64::new(0),
+                AtomicU64::new(0),
+                AtomicU64::new(0),
+                AtomicU64::new(0),
+                AtomicU64::new(0),
+                AtomicU64::new(0),
+                AtomicU64::new(0),
+            ],
+            duration_inf: AtomicU64::new(0),
+            duration_sum_ms: AtomicU64::new(0),
+            duration_count: AtomicU64::new(0),
+        }
+    }
+
+    /// Record a completed run: bump the run counter and the per-status test
+    /// counters, and observe the wall-clock duration in the histogram.
+    pub fn record_run(&self, passed: u32, failed: u32, pending: u32, duration_ms: u32) {
+        self.runs_total.fetch_add(1, Ordering::Relaxed);
+        self.tests_passed.fetch_add(passed as u64, Ordering::Relaxed);
+        self.tests_failed.fetch_add(failed as u64, Ordering::Relaxed);
+        self.tests_pending.fetch_add(pending as u64, Ordering::Relaxed);
+        self.observe_duration(duration_ms as u64);
+    }
+
+    fn observe_duration(&self, ms: u64) {
+        for (bound, bucket) in DURATION_BUCKETS_MS.iter().zip(self.duration_buckets.iter()) {
+            if ms <= *bound {
+                bucket.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        self.duration_inf.fetch_add(1, Ordering::Relaxed);
+        self.duration_sum_ms.fetch_add(ms, Ordering::Relaxed);
+        self.duration_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn inc_running(&self) {
+        self.running.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn dec_running(&self) {
+        self.running.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Render the current metrics as a Prometheus text exposition.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP test_runs_total Total number of test runs.\n");
+        out.push_str("# TYPE test_runs_total counter\n");
+        out.push_str(&format!(
+            "test_runs_total {}\n",
+            self.runs_total.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP test_cases_total Total test cases by outcome.\n");
+        out.push_str("# TYPE test_cases_total counter\n");
+        for (status, counter) in [
+            ("passed", &self.tests_passed),
+            ("failed", &self.tests_failed),
+            ("pending", &self.tests_pending),
+        ] {
+            out.push_str(&format!(
+                "test_cases_total{{status=\"{}\"}} {}\n",
+                status,
+                counter.load(Ordering::Relaxed)
+            ));
+        }
+
+        out.push_str("# HELP test_processes_running Currently running test processes.\n");
+        out.push_str("# TYPE test_processes_running gauge\n");
+        out.push_str(&format!(
+            "test_processes_running {}\n",
+            self.running.load(Ordering::Relaxed)
+        ));
+
+        out.push_str("# HELP test_run_duration_ms Per-run wall-clock duration.\n");
+        out.push_str("# TYPE test_run_duration_ms histogram\n");
+        for (bound, bucket) in DURATION_BUCKETS_MS.iter().zip(self.duration_buckets.iter()) {
+            out.push_str(&format!(
+                "test_run_duration_ms_bucket{{le=\"{}\"}} {}\n",
+                bound,
+                bucket.load(Ordering::Relaxed)
+            ));
+        }
+        out.push_str(&format!(
+            "test_run_duration_ms_bucket{{le=\"+Inf\"}} {}\n",
+            self.duration_inf.load(Ordering::Relaxed)
+        ));
+        out.push_str(&format!(
+            "test_run_duration_ms_sum {}\n",
+            self.duration_sum_ms.load(Ordering::Relaxed)
+        ));
+        out.push_str(&format!(
+            "test_run_duration_ms_count {}\n",
+            self.duration_count.load(Ordering::Relaxed)
+        ));
+
+        out
+    }
+}
+
+/// Global metrics registry.
+pub static METRICS: Metrics = Metrics::new();
+
+/// axum handler rendering the Prometheus exposition.
+pub async fn render_handler() -> String {
+    METRICS.render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_contains_series() {
+        let m = Metrics::new();
+        m.record_run(3, 1, 0, 1200);
+        let text = m.render();
+        assert!(text.contains("test_runs_total 1"));
+        assert!(text.contains("test_cases_total{status=\"passed\"} 3"));
+        assert!(text.contains("test_run_duration_ms_bucket{le=\"5000\"} 1"));
+        assert!(text.contains("test_run_duration_ms_count 1"));
+    }
+}
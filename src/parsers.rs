@@ -0,0 +1,412 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::test_runner::TestRunner;
+
+/// Vendor-agnostic error attached to a failing test case.
+#[derive(Debug, Clone, Serialize)]
+pub struct NormalizedError {
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stack: Option<String>,
+}
+
+/// A single test case in the framework-neutral report.
+#[derive(Debug, Clone, Serialize)]
+pub struct TestCase {
+    pub full_title: String,
+    pub file: Option<String>,
+    pub duration_ms: u64,
+    pub retries: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<NormalizedError>,
+}
+
+/// Aggregate counts for a normalized report.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReportStats {
+    pub suites: u32,
+    pub tests: u32,
+    pub passes: u32,
+    pub failures: u32,
+    pub pending: u32,
+    pub duration_ms: u64,
+}
+
+/// Framework-neutral report produced by every [`TestReportParser`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NormalizedReport {
+    pub stats: ReportStats,
+    pub tests: Vec<TestCase>,
+    pub failures: Vec<TestCase>,
+    pub pending: Vec<TestCase>,
+    pub passes: Vec<TestCase>,
+}
+
+/// Outcome bucket a parser assigns to a case. Threaded explicitly because a
+/// pending/skipped case carries no error and must not be rolled up as a pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseOutcome {
+    Pass,
+    Fail,
+    Pending,
+}
+
+impl NormalizedReport {
+    /// Bucket classified cases into passes/failures/pending and derive the
+    /// counts from them so every parser shares one roll-up path.
+    fn from_classified(tests: Vec<(TestCase, CaseOutcome)>, suites: u32) -> Self {
+        let mut report = NormalizedReport {
+            stats: ReportStats {
+                suites,
+                tests: tests.len() as u32,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        for (case, outcome) in tests {
+            report.stats.duration_ms += case.duration_ms;
+            match outcome {
+                CaseOutcome::Fail => {
+                    report.stats.failures += 1;
+                    report.failures.push(case.clone());
+                }
+                CaseOutcome::Pending => {
+                    report.stats.pending += 1;
+                    report.pending.push(case.clone());
+                }
+                CaseOutcome::Pass => {
+                    report.stats.passes += 1;
+                    report.passes.push(case.clone());
+                }
+            }
+            report.tests.push(case);
+        }
+        report
+    }
+}
+
+/// A parser that turns a framework's raw JSON report into a [`NormalizedReport`].
+pub trait TestReportParser {
+    fn parse(&self, raw: &str) -> Result<NormalizedReport, String>;
+}
+
+/// Select a parser by framework name. Defaults to Cypress for unknown names.
+pub fn parser_for(framework: &str) -> Box<dyn TestReportParser> {
+    match framework.to_lowercase().as_str() {
+        "jest" | "vitest" => Box::new(JestParser),
+        "mocha" => Box::new(MochaParser),
+        "playwright" => Box::new(PlaywrightParser),
+        _ => Box::new(CypressParser),
+    }
+}
+
+/// Cypress (mochawesome-shaped) parser, reusing the existing extraction.
+pub struct CypressParser;
+
+impl TestReportParser for CypressParser {
+    fn parse(&self, raw: &str) -> Result<NormalizedReport, String> {
+        let json = TestRunner::extract_json_from_cypress_output(raw)?;
+        let results = TestRunner::parse_cypress_results(&json)?;
+
+        let to_case = |t: &crate::test_runner::CypressTest| TestCase {
+            full_title: t.full_title.clone(),
+            file: t.file.clone(),
+            duration_ms: t.duration.unwrap_or(0) as u64,
+            retries: t.current_retry,
+            error: t.err.as_ref().map(|e| NormalizedError {
+                message: e.message.clone(),
+                stack: e.stack.clone(),
+            }),
+        };
+
+        Ok(NormalizedReport {
+            stats: ReportStats {
+                suites: results.stats.suites,
+                tests: results.stats.tests,
+                passes: results.stats.passes,
+                failures: results.stats.failures,
+                pending: results.stats.pending,
+                duration_ms: results.stats.duration as u64,
+            },
+            tests: results.tests.iter().map(to_case).collect(),
+            failures: results.failures.iter().map(to_case).collect(),
+            pending: results.pending.iter().map(to_case).collect(),
+            passes: results.passes.iter().map(to_case).collect(),
+        })
+    }
+}
+
+/// Jest/Vitest `--json` parser. Both emit `testResults[].assertionResults[]`.
+pub struct JestParser;
+
+impl TestReportParser for JestParser {
+    fn parse(&self, raw: &str) -> Result<NormalizedReport, String> {
+        let json = TestRunner::extract_json_from_cypress_output(raw)?;
+        let root: Value =
+            serde_json::from_str(&json).map_err(|e| format!("Failed to parse Jest JSON: {}", e))?;
+
+        let mut cases = Vec::new();
+        let mut suites = 0;
+        if let Some(files) = root.get("testResults").and_then(Value::as_array) {
+            suites = files.len() as u32;
+            for file in files {
+                let name = file.get("name").and_then(Value::as_str).map(String::from);
+                if let Some(assertions) = file.get("assertionResults").and_then(Value::as_array) {
+                    for a in assertions {
+                        let status = a.get("status").and_then(Value::as_str).unwrap_or("");
+                        let full_title = a
+                            .get("fullName")
+                            .and_then(Value::as_str)
+                            .unwrap_or("")
+                            .to_string();
+                        let duration_ms =
+                            a.get("duration").and_then(Value::as_u64).unwrap_or(0);
+                        let error = if status == "failed" {
+                            let msg = a
+                                .get("failureMessages")
+                                .and_then(Value::as_array)
+                                .and_then(|m| m.first())
+                                .and_then(Value::as_str)
+                                .unwrap_or("test failed")
+                                .to_string();
+                            Some(NormalizedError {
+                                stack: Some(msg.clone()),
+                                message: msg,
+                            })
+                        } else {
+                            None
+                        };
+                        // Jest/Vitest report non-running cases as pending/todo/skipped.
+                        let outcome = match status {
+                            "failed" => CaseOutcome::Fail,
+                            "pending" | "todo" | "skipped" | "disabled" => CaseOutcome::Pending,
+                            _ => CaseOutcome::Pass,
+                        };
+                        cases.push((
+                            TestCase {
+                                full_title,
+                                file: name.clone(),
+                                duration_ms,
+                                retries: 0,
+                                error,
+                            },
+                            outcome,
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(NormalizedReport::from_classified(cases, suites))
+    }
+}
+
+/// Mocha JSON-reporter parser.
+pub struct MochaParser;
+
+impl TestReportParser for MochaParser {
+    fn parse(&self, raw: &str) -> Result<NormalizedReport, String> {
+        let json = TestRunner::extract_json_from_cypress_output(raw)?;
+        let root: Value = serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse Mocha JSON: {}", e))?;
+
+        let to_case = |t: &Value| TestCase {
+            full_title: t
+                .get("fullTitle")
+                .and_then(Value::as_str)
+                .unwrap_or("")
+                .to_string(),
+            file: t.get("file").and_then(Value::as_str).map(String::from),
+            duration_ms: t.get("duration").and_then(Value::as_u64).unwrap_or(0),
+            retries: t.get("currentRetry").and_then(Value::as_u64).unwrap_or(0) as u32,
+            error: t.get("err").and_then(|e| {
+                let message = e.get("message").and_then(Value::as_str)?.to_string();
+                Some(NormalizedError {
+                    stack: e.get("stack").and_then(Value::as_str).map(String::from),
+                    message,
+                })
+            }),
+        };
+
+        let arr = |key: &str| -> Vec<TestCase> {
+            root.get(key)
+                .and_then(Value::as_array)
+                .map(|a| a.iter().map(&to_case).collect())
+                .unwrap_or_default()
+        };
+        let stats = root.get("stats");
+        let get = |key: &str| stats.and_then(|s| s.get(key)).and_then(Value::as_u64).unwrap_or(0);
+
+        Ok(NormalizedReport {
+            stats: ReportStats {
+                suites: get("suites") as u32,
+                tests: get("tests") as u32,
+                passes: get("passes") as u32,
+                failures: get("failures") as u32,
+                pending: get("pending") as u32,
+                duration_ms: get("duration"),
+            },
+            tests: arr("tests"),
+            failures: arr("failures"),
+            pending: arr("pending"),
+            passes: arr("passes"),
+        })
+    }
+}
+
+/// Playwright JSON-reporter parser. Playwright nests specs under `suites`.
+pub struct PlaywrightParser;
+
+impl PlaywrightParser {
+    /// Recursively walk Playwright's nested suites collecting classified cases.
+    fn walk(node: &Value, cases: &mut Vec<(TestCase, CaseOutcome)>) {
+        if let Some(specs) = node.get("specs").and_then(Value::as_array) {
+            for spec in specs {
+                let title = spec.get("title").and_then(Value::as_str).unwrap_or("");
+                let file = spec.get("file").and_then(Value::as_str).map(String::from);
+                if let Some(tests) = spec.get("tests").and_then(Value::as_array) {
+                    for t in tests {
+                        let results = t.get("results").and_then(Value::as_array);
+                        let duration_ms = results
+                            .and_then(|r| r.last())
+                            .and_then(|r| r.get("duration"))
+                            .and_then(Value::as_u64)
+                            .unwrap_or(0);
+                        let retries = results.map(|r| r.len().saturating_sub(1) as u32).unwrap_or(0);
+                        let status = t.get("status").and_then(Value::as_str).unwrap_or("");
+                        let error = if status == "unexpected" || status == "failed" {
+                            let msg = results
+                                .and_then(|r| r.last())
+                                .and_then(|r| r.get("error"))
+                                .and_then(|e| e.get("message"))
+                                .and_then(Value::as_str)
+                                .unwrap_or("test failed")
+                                .to_string();
+                            Some(NormalizedError {
+                                stack: Some(msg.clone()),
+                                message: msg,
+                            })
+                        } else {
+                            None
+                        };
+                        // Playwright marks unrun specs `skipped`; everything that
+                        // is neither failed nor skipped ran as expected.
+                        let outcome = match status {
+                            "unexpected" | "failed" => CaseOutcome::Fail,
+                            "skipped" => CaseOutcome::Pending,
+                            _ => CaseOutcome::Pass,
+                        };
+                        cases.push((
+                            TestCase {
+                                full_title: title.to_string(),
+                                file: file.clone(),
+                                duration_ms,
+                                retries,
+                                error,
+                            },
+                            outcome,
+                        ));
+                    }
+                }
+            }
+        }
+        if let Some(children) = node.get("suites").and_then(Value::as_array) {
+            for child in children {
+                Self::walk(child, cases);
+            }
+        }
+    }
+}
+
+impl TestReportParser for PlaywrightParser {
+    fn parse(&self, raw: &str) -> Result<NormalizedReport, String> {
+        let json = TestRunner::extract_json_from_cypress_output(raw)?;
+        let root: Value = serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse Playwright JSON: {}", e))?;
+
+        let mut cases = Vec::new();
+        if let Some(suites) = root.get("suites").and_then(Value::as_array) {
+            for suite in suites {
+                Self::walk(suite, &mut cases);
+            }
+        }
+        let suite_count = root
+            .get("suites")
+            .and_then(Value::as_array)
+            .map(|s| s.len() as u32)
+            .unwrap_or(0);
+
+        Ok(NormalizedReport::from_classified(cases, suite_count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jest_parser() {
+        let raw = r#"{
+            "testResults": [{
+                "name": "/app/sum.test.js",
+                "assertionResults": [
+                    {"fullName": "sum adds", "status": "passed", "duration": 5},
+                    {"fullName": "sum fails", "status": "failed", "duration": 3, "failureMessages": ["expected 1 got 2"]}
+                ]
+            }]
+        }"#;
+        let report = parser_for("jest").parse(raw).unwrap();
+        assert_eq!(report.stats.tests, 2);
+        assert_eq!(report.stats.passes, 1);
+        assert_eq!(report.stats.failures, 1);
+        assert_eq!(report.failures[0].full_title, "sum fails");
+    }
+
+    #[test]
+    fn test_jest_parser_counts_pending() {
+        let raw = r#"{
+            "testResults": [{
+                "name": "/app/sum.test.js",
+                "assertionResults": [
+                    {"fullName": "sum adds", "status": "passed", "duration": 5},
+                    {"fullName": "sum later", "status": "todo", "duration": 0},
+                    {"fullName": "sum skipped", "status": "skipped", "duration": 0}
+                ]
+            }]
+        }"#;
+        let report = parser_for("jest").parse(raw).unwrap();
+        assert_eq!(report.stats.passes, 1);
+        assert_eq!(report.stats.pending, 2);
+        assert_eq!(report.stats.failures, 0);
+    }
+
+    #[test]
+    fn test_mocha_parser() {
+        let raw = r#"{
+            "stats": {"suites": 1, "tests": 1, "passes": 0, "pending": 0, "failures": 1, "duration": 12},
+            "tests": [{"fullTitle": "a b", "file": "t.js", "duration": 12, "err": {"message": "boom", "stack": "at t.js:1:1"}}],
+            "failures": [{"fullTitle": "a b", "file": "t.js", "duration": 12, "err": {"message": "boom"}}],
+            "pending": [], "passes": []
+        }"#;
+        let report = parser_for("mocha").parse(raw).unwrap();
+        assert_eq!(report.stats.failures, 1);
+        assert_eq!(report.failures[0].error.as_ref().unwrap().message, "boom");
+    }
+
+    #[test]
+    fn test_playwright_parser() {
+        let raw = r#"{
+            "suites": [{
+                "specs": [{
+                    "title": "loads", "file": "home.spec.ts",
+                    "tests": [{"status": "expected", "results": [{"duration": 42}]}]
+                }],
+                "suites": []
+            }]
+        }"#;
+        let report = parser_for("playwright").parse(raw).unwrap();
+        assert_eq!(report.stats.tests, 1);
+        assert_eq!(report.passes[0].duration_ms, 42);
+    }
+}
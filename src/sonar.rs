@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+
+use crate::test_runner::{CypressResults, CypressTest};
+
+/// Outcome of a test as represented in SonarQube's Generic Test Execution
+/// report: the element that wraps a `<testCase>` body.
+enum Outcome {
+    Passed,
+    Failed,
+    Errored,
+    Skipped,
+}
+
+/// Escape a string for use in an XML attribute value.
+fn escape_attr(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Classify a test into a Sonar outcome. Cypress assertion failures map to
+/// `<failure>`, other thrown errors to `<error>`, pending specs to `<skipped>`.
+fn outcome_of(test: &CypressTest, pending: bool) -> Outcome {
+    if pending {
+        return Outcome::Skipped;
+    }
+    match &test.err {
+        None => Outcome::Passed,
+        Some(err) if err.name.contains("AssertionError") => Outcome::Failed,
+        Some(_) => Outcome::Errored,
+    }
+}
+
+/// Render a single `<testCase>` element for `test`.
+fn test_case_xml(test: &CypressTest, pending: bool) -> String {
+    let name = escape_attr(&test.full_title);
+    let duration = test.duration.unwrap_or(0);
+    match outcome_of(test, pending) {
+        Outcome::Passed => {
+            format!("    <testCase name=\"{}\" duration=\"{}\"/>\n", name, duration)
+        }
+        Outcome::Skipped => {
+            let msg = escape_attr(test.err.as_ref().map(|e| e.message.as_str()).unwrap_or("pending"));
+            format!(
+                "    <testCase name=\"{}\" duration=\"{}\">\n      <skipped message=\"{}\"/>\n    </testCase>\n",
+                name, duration, msg
+            )
+        }
+        Outcome::Failed | Outcome::Errored => {
+            let err = test.err.as_ref();
+            let message = escape_attr(err.map(|e| e.message.as_str()).unwrap_or(""));
+            // Prefer the code frame text as the stack body when present.
+            let stack = err
+                .and_then(|e| e.code_frame.as_ref())
+                .map(|cf| cf.frame.clone())
+                .or_else(|| err.map(|e| e.message.clone()))
+                .unwrap_or_default();
+            let tag = match outcome_of(test, pending) {
+                Outcome::Errored => "error",
+                _ => "failure",
+            };
+            format!(
+                "    <testCase name=\"{}\" duration=\"{}\">\n      <{tag} message=\"{}\"><![CDATA[{}]]></{tag}>\n    </testCase>\n",
+                name, duration, message, stack
+            )
+        }
+    }
+}
+
+/// Convert parsed Cypress results into SonarQube Generic Test Execution XML.
+///
+/// `path_prefix` is prepended to each test's file path so the reported paths
+/// match the repository root SonarQube scans.
+pub fn to_sonar_xml(results: &CypressResults, path_prefix: &str) -> String {
+    // Group tests by file, preserving a deterministic (sorted) file order.
+    let mut by_file: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    let mut push = |test: &CypressTest, pending: bool| {
+        let file = test.file.clone().unwrap_or_else(|| "unknown".to_string());
+        let path = if path_prefix.is_empty() {
+            file
+        } else {
+            format!("{}/{}", path_prefix.trim_end_matches('/'), file)
+        };
+        by_file.entry(path).or_default().push(test_case_xml(test, pending));
+    };
+
+    for test in &results.passes {
+        push(test, false);
+    }
+    for test in &results.failures {
+        push(test, false);
+    }
+    for test in &results.pending {
+        push(test, true);
+    }
+
+    let mut xml = String::from("<testExecutions version=\"1\">\n");
+    for (path, cases) in by_file {
+        xml.push_str(&format!("  <file path=\"{}\">\n", escape_attr(&path)));
+        for case in cases {
+            xml.push_str(&case);
+        }
+        xml.push_str("  </file>\n");
+    }
+    xml.push_str("</testExecutions>\n");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_runner::{CypressError, CypressStats, CypressTest};
+
+    fn test_with(file: &str, err: Option<CypressError>) -> CypressTest {
+        CypressTest {
+            title: "t".to_string(),
+            full_title: "suite does a thing".to_string(),
+            file: Some(file.to_string()),
+            duration: Some(42),
+            current_retry: 0,
+            err,
+            attempts: vec![],
+            classification: None,
+        }
+    }
+
+    #[test]
+    fn test_to_sonar_xml() {
+        let results = CypressResults {
+            stats: CypressStats {
+                suites: 1,
+                tests: 2,
+                passes: 1,
+                pending: 0,
+                failures: 1,
+                start: String::new(),
+                end: String::new(),
+                duration: 0,
+                flaky: 0,
+            },
+            tests: vec![],
+            pending: vec![],
+            passes: vec![test_with("spec/a.cy.js", None)],
+            failures: vec![test_with(
+                "spec/a.cy.js",
+                Some(CypressError {
+                    message: "expected 1 to equal 2".to_string(),
+                    name: "AssertionError".to_string(),
+                    code_frame: None,
+                    stack: None,
+                    frames: vec![],
+                }),
+            )],
+        };
+
+        let xml = to_sonar_xml(&results, "frontend");
+        assert!(xml.contains("<testExecutions version=\"1\">"));
+        assert!(xml.contains("<file path=\"frontend/spec/a.cy.js\">"));
+        assert!(xml.contains("duration=\"42\""));
+        assert!(xml.contains("<failure message=\"expected 1 to equal 2\">"));
+        assert!(xml.contains("<![CDATA["));
+    }
+}
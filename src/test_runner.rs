@@ -22,6 +22,51 @@ pub struct TestRunnerArgs {
         example = "[37, 87]"
     )]
     pub line_numbers: Option<Vec<i32>>,
+
+    #[schemars(
+        description = "Optional seed for reproducible test ordering; a random seed is chosen and echoed back when omitted",
+        example = "12345"
+    )]
+    pub seed: Option<u64>,
+
+    #[schemars(
+        description = "Optional directory to persist raw stdout/stderr and parsed JSON artifacts for this run",
+        example = "tmp/test-logs"
+    )]
+    pub output_dir: Option<String>,
+
+    #[schemars(
+        description = "Omit the verbose raw/filtered body from the returned text, keeping only the summary",
+        example = "true"
+    )]
+    pub skip_body: Option<bool>,
+}
+
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct RspecSuiteArgs {
+    #[schemars(
+        description = "Directory or glob pattern to collect '*_spec.rb' files from",
+        example = "spec/models"
+    )]
+    pub path: String,
+
+    #[schemars(
+        description = "Optional glob patterns to include (matched against collected paths)",
+        example = "[\"spec/models/**\"]"
+    )]
+    pub include: Option<Vec<String>>,
+
+    #[schemars(
+        description = "Optional glob patterns to exclude (e.g. slow integration specs)",
+        example = "[\"spec/integration/**\"]"
+    )]
+    pub exclude: Option<Vec<String>>,
+
+    #[schemars(
+        description = "Optional seed for reproducible file ordering; a random seed is chosen and echoed back when omitted",
+        example = "12345"
+    )]
+    pub seed: Option<u64>,
 }
 
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
@@ -31,6 +76,36 @@ pub struct CypressArgs {
         example = "cypress/e2e/user-login.cy.js"
     )]
     pub file: String,
+
+    #[schemars(
+        description = "Optional directory to persist raw stdout/stderr and parsed JSON artifacts for this run",
+        example = "tmp/test-logs"
+    )]
+    pub output_dir: Option<String>,
+
+    #[schemars(
+        description = "Omit the verbose raw/filtered body from the returned text, keeping only the summary",
+        example = "true"
+    )]
+    pub skip_body: Option<bool>,
+
+    #[schemars(
+        description = "Serialization format for the results: 'json' (default) or 'sonar' (SonarQube Generic Test Execution XML)",
+        example = "sonar"
+    )]
+    pub format: Option<String>,
+
+    #[schemars(
+        description = "Path prefix prepended to spec file paths in the Sonar report to match the repo root",
+        example = "frontend"
+    )]
+    pub sonar_path_prefix: Option<String>,
+
+    #[schemars(
+        description = "Report format to parse: 'cypress' (default), 'jest', 'vitest', 'mocha', or 'playwright'",
+        example = "playwright"
+    )]
+    pub framework: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -43,9 +118,13 @@ pub struct CypressStats {
     pub start: String,
     pub end: String,
     pub duration: u32,
+    /// Number of tests that failed at least once but ultimately passed. Derived
+    /// during filtering rather than read from Cypress, so defaults to zero.
+    #[serde(default)]
+    pub flaky: u32,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CypressCodeFrame {
     pub line: u32,
     pub column: u32,
@@ -59,12 +138,110 @@ pub struct CypressCodeFrame {
     pub language: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// A single parsed stack-trace frame. `navigable` is true when `file` maps to a
+/// real source file on disk rather than an internal Cypress/runtime frame.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StackFrame {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub navigable: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CypressError {
     pub message: String,
     pub name: String,
     #[serde(rename = "codeFrame")]
     pub code_frame: Option<CypressCodeFrame>,
+    /// Raw stack string as emitted by the reporter, when present.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub stack: Option<String>,
+    /// Stack parsed into ordered, navigable frames (populated during filtering).
+    #[serde(default)]
+    pub frames: Vec<StackFrame>,
+}
+
+/// Parse a raw V8-style stack string into ordered frames.
+///
+/// Handles both `at fn (file:line:col)` and bare `at file:line:col` forms.
+/// Frames whose location is an `http(s)://` URL are dropped (not navigable to a
+/// file); internal frames (e.g. `node_modules/cypress`) are kept but flagged
+/// `navigable = false` so clients can render only real source locations.
+pub fn parse_stack(stack: &str) -> Vec<StackFrame> {
+    let mut frames = Vec::new();
+
+    for raw in stack.lines() {
+        let line = raw.trim();
+        let Some(rest) = line.strip_prefix("at ") else {
+            continue;
+        };
+
+        // Split the optional `function (location)` form from a bare location.
+        let (function, location) = match (rest.find('('), rest.ends_with(')')) {
+            (Some(open), true) => (
+                Some(rest[..open].trim().to_string()),
+                rest[open + 1..rest.len() - 1].trim(),
+            ),
+            _ => (None, rest),
+        };
+
+        // URL-based frames are not navigable to a file on disk.
+        if location.starts_with("http://") || location.starts_with("https://") {
+            continue;
+        }
+
+        // The trailing `:line:col` is parsed off the end so paths containing
+        // colons (e.g. Windows drives) are tolerated.
+        let (file, line_no, col_no) = match split_location(location) {
+            Some(parts) => parts,
+            None => continue,
+        };
+
+        let navigable = !file.contains("node_modules") && !file.starts_with('<');
+        frames.push(StackFrame {
+            function,
+            file,
+            line: line_no,
+            column: col_no,
+            navigable,
+        });
+    }
+
+    frames
+}
+
+/// Split a `file:line:col` location from the right, returning the file path and
+/// the numeric line/column.
+fn split_location(location: &str) -> Option<(String, u32, u32)> {
+    let col_idx = location.rfind(':')?;
+    let col: u32 = location[col_idx + 1..].parse().ok()?;
+    let without_col = &location[..col_idx];
+    let line_idx = without_col.rfind(':')?;
+    let line: u32 = without_col[line_idx + 1..].parse().ok()?;
+    let file = without_col[..line_idx].to_string();
+    Some((file, line, col))
+}
+
+/// A single execution attempt of a test, as recorded by Cypress when retries
+/// are enabled. The ordered list of attempts lets us distinguish a flaky test
+/// (eventually passed) from one that failed every time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attempt {
+    pub state: String,
+    pub duration: Option<u32>,
+    pub err: Option<CypressError>,
+}
+
+/// Derived per-test outcome once retries are taken into account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Classification {
+    Passed,
+    Failed,
+    Flaky,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -77,6 +254,42 @@ pub struct CypressTest {
     #[serde(rename = "currentRetry")]
     pub current_retry: u32,
     pub err: Option<CypressError>,
+    #[serde(default)]
+    pub attempts: Vec<Attempt>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub classification: Option<Classification>,
+}
+
+impl CypressTest {
+    /// Classify this test from its attempts array, falling back to `err`
+    /// presence when no attempts were recorded (retries disabled).
+    ///
+    /// A `before all` hook failure aborts retries, leaving a single failing
+    /// attempt (or `skipped` placeholders) — such a test classifies as
+    /// `Failed`, never `Flaky`, because no attempt ever passed.
+    pub fn classify(&self) -> Classification {
+        // Hook-abort placeholders carry `skipped`/`pending` state and must not
+        // be treated as real retries.
+        let states: Vec<&str> = self
+            .attempts
+            .iter()
+            .map(|a| a.state.as_str())
+            .filter(|s| *s == "passed" || *s == "failed")
+            .collect();
+
+        match states.last() {
+            Some(&"passed") if states.iter().any(|s| *s == "failed") => Classification::Flaky,
+            Some(&"passed") => Classification::Passed,
+            Some(&"failed") => Classification::Failed,
+            _ => {
+                if self.err.is_some() {
+                    Classification::Failed
+                } else {
+                    Classification::Passed
+                }
+            }
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -88,6 +301,81 @@ pub struct CypressResults {
     pub passes: Vec<CypressTest>,
 }
 
+/// Outcome of a single test case, normalized across frameworks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    Pending,
+    Skipped,
+}
+
+/// A single normalized test case, framework-agnostic.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TestCaseResult {
+    pub name: String,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub status: TestStatus,
+    pub failure_message: Option<String>,
+    pub failure_location: Option<String>,
+}
+
+/// Roll-up counts for a normalized run, mirroring a reporter summary block.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TestSummary {
+    pub total: u32,
+    pub passed: u32,
+    pub failed: u32,
+    pub pending: u32,
+    pub skipped: u32,
+    pub duration_ms: u64,
+}
+
+/// Shared result shape produced by every runner regardless of framework.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NormalizedResults {
+    pub summary: TestSummary,
+    pub cases: Vec<TestCaseResult>,
+    /// Local Git state at run time, absent when the run directory is not a repo.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub git: Option<crate::git::GitContext>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RspecSummary {
+    pub duration: f64,
+    pub example_count: u32,
+    pub failure_count: u32,
+    pub pending_count: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RspecException {
+    pub class: String,
+    pub message: String,
+    #[serde(default)]
+    pub backtrace: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RspecExample {
+    pub description: String,
+    pub full_description: String,
+    pub file_path: String,
+    pub line_number: u32,
+    pub status: String,
+    #[serde(default)]
+    pub exception: Option<RspecException>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RspecResults {
+    pub examples: Vec<RspecExample>,
+    pub summary: RspecSummary,
+}
+
 #[derive(Debug)]
 pub struct ParsedFilePath {
     pub file_path: String,
@@ -145,16 +433,21 @@ impl ParsedFilePath {
         Ok(())
     }
 
-    fn validate_cypress_file_path(path: &str) -> Result<(), String> {
-        // Block dangerous characters first
+    /// Path-safety checks shared by every entry point: reject NUL and newline
+    /// characters and `../` traversal. Callers layer any extension rules on top.
+    fn validate_generic_file_path(path: &str) -> Result<(), String> {
         if path.contains('\0') || path.contains('\n') {
             return Err("Invalid characters in file path".to_string());
         }
-
-        // Prevent path traversal
         if path.contains("../") {
             return Err("Path traversal not allowed".to_string());
         }
+        Ok(())
+    }
+
+    fn validate_cypress_file_path(path: &str) -> Result<(), String> {
+        // Block dangerous characters and traversal first.
+        Self::validate_generic_file_path(path)?;
 
         // Remove optional "./" prefix for validation
         let clean_path = path.strip_prefix("./").unwrap_or(path);
@@ -184,6 +477,185 @@ impl ParsedFilePath {
             line_numbers: vec![], // Cypress doesn't use line numbers
         })
     }
+
+    /// Validate a spec path for a non-Cypress framework: only the shared
+    /// safety checks apply, since Jest/Vitest/Mocha/Playwright use their own
+    /// file-naming conventions (`*.test.js`, `*.spec.ts`, …).
+    fn from_framework_args(file_path: &str) -> Result<Self, String> {
+        if file_path.is_empty() {
+            return Err("Empty file path".to_string());
+        }
+
+        Self::validate_generic_file_path(file_path)?;
+
+        Ok(ParsedFilePath {
+            file_path: file_path.to_string(),
+            line_numbers: vec![],
+        })
+    }
+}
+
+/// Small seeded PRNG (SplitMix64) used to shuffle test ordering reproducibly.
+///
+/// A fixed seed yields a fixed ordering so a failing run can be replayed; the
+/// effective seed is always echoed back in the tool output.
+struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Fisher–Yates shuffle driven by this generator.
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        if items.len() < 2 {
+            return;
+        }
+        for i in (1..items.len()).rev() {
+            let j = (self.next() % (i as u64 + 1)) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Derive a process-random seed when the caller does not supply one.
+fn random_seed() -> u64 {
+    use std::hash::{BuildHasher, Hasher};
+    std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish()
+}
+
+/// Match a path against a glob pattern supporting `*`, `?` and `**`.
+///
+/// `**` matches across directory separators; a single `*` matches any run of
+/// characters that does not cross a `/`. This is intentionally small — enough
+/// to target subtrees like `spec/models/**` without pulling in a glob crate.
+fn glob_match(pattern: &str, path: &str) -> bool {
+    fn matches(pat: &[u8], text: &[u8]) -> bool {
+        if pat.is_empty() {
+            return text.is_empty();
+        }
+        match pat[0] {
+            b'*' => {
+                // `**` matches any characters including path separators.
+                if pat.get(1) == Some(&b'*') {
+                    let rest = &pat[2..];
+                    (0..=text.len()).any(|i| matches(rest, &text[i..]))
+                } else {
+                    let rest = &pat[1..];
+                    let mut i = 0;
+                    loop {
+                        if matches(rest, &text[i..]) {
+                            return true;
+                        }
+                        if i >= text.len() || text[i] == b'/' {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                }
+            }
+            b'?' => !text.is_empty() && text[0] != b'/' && matches(&pat[1..], &text[1..]),
+            c => !text.is_empty() && text[0] == c && matches(&pat[1..], &text[1..]),
+        }
+    }
+
+    matches(pattern.as_bytes(), path.as_bytes())
+}
+
+/// Split a possibly-glob `path` into the deepest directory that is free of
+/// glob metacharacters (the walk root) and, when the path actually contained a
+/// glob, the full pattern to match collected files against.
+///
+/// `spec/models/**/*_spec.rb` → (`spec/models`, Some(pattern)); a plain
+/// directory like `spec/models` → (`spec/models`, None).
+fn split_glob_root(path: &str) -> (String, Option<String>) {
+    let Some(glob_at) = path.find(['*', '?']) else {
+        return (path.to_string(), None);
+    };
+    let base = match path[..glob_at].rfind('/') {
+        Some(slash) => path[..slash].to_string(),
+        None => ".".to_string(),
+    };
+    (base, Some(path.to_string()))
+}
+
+/// Walk `root` collecting every RSpec spec file, applying the same path-safety
+/// validation used for single-file runs and optional include/exclude globs.
+///
+/// `root` may itself be a glob (e.g. `spec/models/**/*_spec.rb`): the walk
+/// starts from its deepest glob-free directory and the pattern is applied as an
+/// additional include filter on top of `include`.
+fn collect_spec_files(
+    root: &str,
+    include: &[String],
+    exclude: &[String],
+) -> Result<Vec<String>, String> {
+    // Guard the entry point exactly like a single target before touching disk.
+    if root.contains('\0') || root.contains('\n') {
+        return Err("Invalid characters in file path".to_string());
+    }
+    if root.contains("../") {
+        return Err("Path traversal not allowed".to_string());
+    }
+
+    let (walk_root, root_glob) = split_glob_root(root);
+
+    let mut collected = Vec::new();
+    let mut stack = vec![std::path::PathBuf::from(&walk_root)];
+
+    while let Some(dir) = stack.pop() {
+        let entries = std::fs::read_dir(&dir)
+            .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+            let file_type = entry
+                .file_type()
+                .map_err(|e| format!("Failed to stat entry: {}", e))?;
+            let path = entry.path();
+            if file_type.is_dir() {
+                stack.push(path);
+                continue;
+            }
+            let path_str = path.to_string_lossy().to_string();
+            if ParsedFilePath::validate_file_path(&path_str).is_err() {
+                continue;
+            }
+            if let Some(pattern) = &root_glob {
+                // A glob with no directory part walks from ".", whose entries
+                // come back as "./name"; match those without the prefix.
+                let candidate = if pattern.starts_with("./") {
+                    path_str.as_str()
+                } else {
+                    path_str.strip_prefix("./").unwrap_or(&path_str)
+                };
+                if !glob_match(pattern, candidate) {
+                    continue;
+                }
+            }
+            if !include.is_empty() && !include.iter().any(|p| glob_match(p, &path_str)) {
+                continue;
+            }
+            if exclude.iter().any(|p| glob_match(p, &path_str)) {
+                continue;
+            }
+            collected.push(path_str);
+        }
+    }
+
+    collected.sort();
+    Ok(collected)
 }
 
 #[derive(Clone)]
@@ -191,6 +663,7 @@ pub struct TestRunner {
     tool_router: ToolRouter<TestRunner>,
     rspec_command: String,
     cypress_command: String,
+    webhook: Option<crate::webhook::WebhookConfig>,
 }
 
 #[tool_router]
@@ -200,49 +673,430 @@ impl TestRunner {
             tool_router: Self::tool_router(),
             rspec_command,
             cypress_command,
+            webhook: None,
         }
     }
 
-    fn extract_json_from_cypress_output(output: &str) -> Result<String, String> {
-        // Find the first opening brace which marks the start of JSON
-        if let Some(start_pos) = output.find('{') {
-            let json_portion = &output[start_pos..];
-            Ok(json_portion.to_string())
-        } else {
-            Err("No JSON found in Cypress output".to_string())
+    /// Attach an incoming-webhook notification target. Run summaries are posted
+    /// best-effort after each Cypress run is parsed.
+    pub fn with_webhook(mut self, webhook: crate::webhook::WebhookConfig) -> Self {
+        self.webhook = Some(webhook);
+        self
+    }
+
+    /// Return the first complete top-level JSON object in `output`, tolerating
+    /// any log lines that precede or follow it — including a trailing line that
+    /// itself contains an unbalanced `{` (Electron/dbus warnings). Scanning
+    /// stops as soon as the first object closes, so later garbage is never read.
+    ///
+    /// Unterminated-object reporting is intentionally left to
+    /// [`extract_all_json_objects`]; here an opening brace that never closes is
+    /// treated as "no JSON found" rather than an error.
+    pub(crate) fn extract_json_from_cypress_output(output: &str) -> Result<String, String> {
+        let bytes = output.as_bytes();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            // Skip anything up to the next opening brace.
+            if bytes[i] != b'{' {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            let mut depth = 0usize;
+            let mut in_string = false;
+            let mut escaped = false;
+
+            while i < bytes.len() {
+                let b = bytes[i];
+                if in_string {
+                    if escaped {
+                        escaped = false;
+                    } else if b == b'\\' {
+                        escaped = true;
+                    } else if b == b'"' {
+                        in_string = false;
+                    }
+                } else {
+                    match b {
+                        b'"' => in_string = true,
+                        b'{' => depth += 1,
+                        b'}' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return Ok(output[start..=i].to_string());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                i += 1;
+            }
+
+            // The first object opened but never closed; stop scanning so any
+            // later content is ignored as garbage.
+            break;
+        }
+
+        Err("No JSON found in Cypress output".to_string())
+    }
+
+    /// Scan `output` for every top-level JSON object, tolerating log lines
+    /// interleaved before, between, and after the objects (Electron/dbus
+    /// warnings commonly trail the reporter JSON) and NDJSON-style reporters
+    /// that print one object per line.
+    ///
+    /// The scanner tracks brace depth while respecting string literals and
+    /// escaped quotes, so braces inside strings do not skew the balance. Leading
+    /// BOM and whitespace before an object are skipped. An object that opens but
+    /// never closes yields a clear error.
+    pub(crate) fn extract_all_json_objects(output: &str) -> Result<Vec<String>, String> {
+        let bytes = output.as_bytes();
+        let mut objects = Vec::new();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            // Skip anything up to the next opening brace.
+            if bytes[i] != b'{' {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            let mut depth = 0usize;
+            let mut in_string = false;
+            let mut escaped = false;
+            let mut closed = false;
+
+            while i < bytes.len() {
+                let b = bytes[i];
+                if in_string {
+                    if escaped {
+                        escaped = false;
+                    } else if b == b'\\' {
+                        escaped = true;
+                    } else if b == b'"' {
+                        in_string = false;
+                    }
+                } else {
+                    match b {
+                        b'"' => in_string = true,
+                        b'{' => depth += 1,
+                        b'}' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                objects.push(output[start..=i].to_string());
+                                i += 1;
+                                closed = true;
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                i += 1;
+            }
+
+            if !closed {
+                return Err("Unterminated JSON object in output".to_string());
+            }
         }
+
+        Ok(objects)
     }
 
-    fn parse_cypress_results(json_str: &str) -> Result<CypressResults, String> {
+    pub(crate) fn parse_cypress_results(json_str: &str) -> Result<CypressResults, String> {
         serde_json::from_str(json_str)
             .map_err(|e| format!("Failed to parse Cypress JSON: {}", e))
     }
 
-    fn filter_cypress_results(results: CypressResults) -> CypressResults {
+    /// Parse and merge several Cypress result documents from one logical run
+    /// (e.g. parallelized shards) into a single consolidated tree.
+    ///
+    /// `suites` and `duration` are summed, the run span covers min-start to
+    /// max-end, and the test arrays are concatenated with de-duplication by
+    /// `fullTitle`+`file` so a spec retried across shards appears once; the
+    /// per-outcome counts are then taken from those arrays. The return shape is identical to
+    /// the single-document path so every downstream formatter works unchanged.
+    fn parse_and_merge(inputs: &[&str]) -> Result<CypressResults, String> {
+        if inputs.is_empty() {
+            return Err("No result documents to merge".to_string());
+        }
+
+        let mut merged: Option<CypressResults> = None;
+        for input in inputs {
+            let next = Self::parse_cypress_results(input)?;
+            merged = Some(match merged {
+                None => next,
+                Some(acc) => Self::merge_two(acc, next),
+            });
+        }
+
+        // Unwrap is safe: inputs is non-empty so merged was set at least once.
+        let mut merged = merged.unwrap();
+        Self::dedup_by_full_title_file(&mut merged.tests);
+        Self::dedup_by_full_title_file(&mut merged.pending);
+        Self::dedup_by_full_title_file(&mut merged.failures);
+        Self::dedup_by_full_title_file(&mut merged.passes);
+
+        // Recompute the per-outcome counts from the de-duplicated arrays so the
+        // stats stay consistent with the cases a spec retried across shards is
+        // counted once. `suites` and `duration` remain summed across shards.
+        merged.stats.tests = merged.tests.len() as u32;
+        merged.stats.pending = merged.pending.len() as u32;
+        merged.stats.failures = merged.failures.len() as u32;
+        merged.stats.passes = merged.passes.len() as u32;
+        Ok(merged)
+    }
+
+    fn merge_two(mut acc: CypressResults, next: CypressResults) -> CypressResults {
+        acc.stats.suites += next.stats.suites;
+        acc.stats.tests += next.stats.tests;
+        acc.stats.passes += next.stats.passes;
+        acc.stats.pending += next.stats.pending;
+        acc.stats.failures += next.stats.failures;
+        acc.stats.duration += next.stats.duration;
+
+        // Timestamps are ISO-8601 and sort lexicographically; ignore empties.
+        if acc.stats.start.is_empty() || (!next.stats.start.is_empty() && next.stats.start < acc.stats.start) {
+            acc.stats.start = next.stats.start;
+        }
+        if next.stats.end > acc.stats.end {
+            acc.stats.end = next.stats.end;
+        }
+
+        acc.tests.extend(next.tests);
+        acc.pending.extend(next.pending);
+        acc.failures.extend(next.failures);
+        acc.passes.extend(next.passes);
+        acc
+    }
+
+    fn dedup_by_full_title_file(tests: &mut Vec<CypressTest>) {
+        let mut seen = std::collections::HashSet::new();
+        tests.retain(|t| seen.insert((t.full_title.clone(), t.file.clone())));
+    }
+
+    fn filter_cypress_results(mut results: CypressResults) -> CypressResults {
         let filter_test = |test: CypressTest| -> CypressTest {
+            let classification = test.classify();
+            // Prefer the test-level error, but fall back to the most recent
+            // attempt that recorded one so a flaky/failed test still surfaces a
+            // message even when Cypress only attached it to an attempt.
+            let effective_err = test
+                .err
+                .or_else(|| test.attempts.iter().rev().find_map(|a| a.err.clone()));
+            // Likewise, when the test carries no top-level duration use the
+            // longest recorded attempt duration.
+            let duration = test
+                .duration
+                .or_else(|| test.attempts.iter().filter_map(|a| a.duration).max());
             CypressTest {
                 title: test.title,
                 full_title: test.full_title,
                 file: test.file,
-                duration: test.duration,
+                duration,
                 current_retry: test.current_retry,
-                err: test.err.map(|err| CypressError {
-                    message: err.message,
-                    name: err.name,
-                    code_frame: err.code_frame,
+                err: effective_err.map(|err| {
+                    let frames = err.stack.as_deref().map(parse_stack).unwrap_or_default();
+                    CypressError {
+                        message: err.message,
+                        name: err.name,
+                        code_frame: err.code_frame,
+                        stack: err.stack,
+                        frames,
+                    }
                 }),
+                attempts: test.attempts,
+                classification: Some(classification),
             }
         };
 
+        let tests: Vec<CypressTest> = results.tests.into_iter().map(filter_test).collect();
+        // Roll up the flaky count so clients can ask "which tests are flaky?"
+        // without re-deriving it from the attempts arrays.
+        let flaky = tests
+            .iter()
+            .filter(|t| t.classification == Some(Classification::Flaky))
+            .count() as u32;
+        results.stats.flaky = flaky;
+
         CypressResults {
             stats: results.stats,
-            tests: results.tests.into_iter().map(filter_test).collect(),
+            tests,
             pending: results.pending.into_iter().map(filter_test).collect(),
             failures: results.failures.into_iter().map(filter_test).collect(),
             passes: results.passes.into_iter().map(filter_test).collect(),
         }
     }
 
+    fn parse_rspec_results(json_str: &str) -> Result<RspecResults, String> {
+        serde_json::from_str(json_str)
+            .map_err(|e| format!("Failed to parse RSpec JSON: {}", e))
+    }
+
+    fn filter_rspec_results(results: RspecResults) -> RspecResults {
+        let filter_example = |example: RspecExample| -> RspecExample {
+            RspecExample {
+                description: example.description,
+                full_description: example.full_description,
+                file_path: example.file_path,
+                line_number: example.line_number,
+                status: example.status,
+                exception: example.exception.map(|ex| RspecException {
+                    class: ex.class,
+                    message: ex.message,
+                    backtrace: ex.backtrace,
+                }),
+            }
+        };
+
+        RspecResults {
+            examples: results.examples.into_iter().map(filter_example).collect(),
+            summary: results.summary,
+        }
+    }
+
+    /// Persist a run's raw and parsed artifacts to `dir` under timestamped file
+    /// names, creating the directory if needed, and return the written paths.
+    ///
+    /// `dir` is validated with the same path-safety rules as spec paths: NUL
+    /// and newline characters and `../` traversal are rejected. Absolute paths
+    /// are not rejected.
+    fn persist_artifacts(
+        dir: &str,
+        prefix: &str,
+        stdout: &str,
+        stderr: &str,
+        parsed_json: &str,
+    ) -> Result<Vec<String>, String> {
+        if dir.contains('\0') || dir.contains('\n') {
+            return Err("Invalid characters in output directory".to_string());
+        }
+        if dir.contains("../") {
+            return Err("Path traversal not allowed".to_string());
+        }
+
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create output directory {}: {}", dir, e))?;
+
+        let ts = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+        let base = std::path::Path::new(dir);
+
+        let artifacts = [
+            (format!("{}-{}.stdout.log", prefix, ts), stdout),
+            (format!("{}-{}.stderr.log", prefix, ts), stderr),
+            (format!("{}-{}.json", prefix, ts), parsed_json),
+        ];
+
+        let mut written = Vec::with_capacity(artifacts.len());
+        for (name, contents) in artifacts {
+            let path = base.join(&name);
+            std::fs::write(&path, contents)
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+            written.push(path.to_string_lossy().to_string());
+        }
+        Ok(written)
+    }
+
+    fn success_with_structured(text: String, normalized: &NormalizedResults) -> CallToolResult {
+        let mut result = CallToolResult::success(vec![Content::text(text)]);
+        result.structured_content = serde_json::to_value(normalized).ok();
+        result
+    }
+
+    fn normalize_cypress_results(results: &CypressResults) -> NormalizedResults {
+        let to_case = |test: &CypressTest, status: TestStatus| -> TestCaseResult {
+            let (failure_message, failure_location) = match &test.err {
+                Some(err) => {
+                    let loc = err.code_frame.as_ref().map(|cf| {
+                        format!("{}:{}:{}", cf.relative_file, cf.line, cf.column)
+                    });
+                    (Some(err.message.clone()), loc)
+                }
+                None => (None, None),
+            };
+            TestCaseResult {
+                name: test.full_title.clone(),
+                file: test.file.clone(),
+                line: None,
+                status,
+                failure_message,
+                failure_location,
+            }
+        };
+
+        let mut cases = Vec::new();
+        cases.extend(results.passes.iter().map(|t| to_case(t, TestStatus::Passed)));
+        cases.extend(results.failures.iter().map(|t| to_case(t, TestStatus::Failed)));
+        cases.extend(results.pending.iter().map(|t| to_case(t, TestStatus::Pending)));
+
+        NormalizedResults {
+            summary: TestSummary {
+                total: results.stats.tests,
+                passed: results.stats.passes,
+                failed: results.stats.failures,
+                pending: results.stats.pending,
+                skipped: 0,
+                duration_ms: u64::from(results.stats.duration),
+            },
+            cases,
+            git: None,
+        }
+    }
+
+    fn normalize_rspec_results(results: &RspecResults) -> NormalizedResults {
+        let cases = results
+            .examples
+            .iter()
+            .map(|ex| {
+                let status = match ex.status.as_str() {
+                    "passed" => TestStatus::Passed,
+                    "failed" => TestStatus::Failed,
+                    "pending" => TestStatus::Pending,
+                    _ => TestStatus::Skipped,
+                };
+                let failure_message = ex.exception.as_ref().map(|e| e.message.clone());
+                let failure_location = ex
+                    .exception
+                    .as_ref()
+                    .and_then(|e| e.backtrace.as_ref())
+                    .and_then(|bt| bt.first().cloned());
+                TestCaseResult {
+                    name: ex.full_description.clone(),
+                    file: Some(ex.file_path.clone()),
+                    line: Some(ex.line_number),
+                    status,
+                    failure_message,
+                    failure_location,
+                }
+            })
+            .collect();
+
+        let passed = results
+            .summary
+            .example_count
+            .saturating_sub(results.summary.failure_count)
+            .saturating_sub(results.summary.pending_count);
+
+        NormalizedResults {
+            summary: TestSummary {
+                total: results.summary.example_count,
+                passed,
+                failed: results.summary.failure_count,
+                pending: results.summary.pending_count,
+                skipped: 0,
+                duration_ms: (results.summary.duration * 1000.0) as u64,
+            },
+            cases,
+            git: None,
+        }
+    }
+
     #[tool(
         description = "Run RSpec tests for a specific file with optional line number targeting. Accepts file paths ending in '_spec.rb' with optional array of line numbers"
     )]
@@ -251,6 +1105,7 @@ impl TestRunner {
         Parameters(args): Parameters<TestRunnerArgs>,
     ) -> Result<CallToolResult, McpError> {
         // Parse the file path and validate format
+        let effective_seed = args.seed.unwrap_or_else(random_seed);
         let line_numbers = args.line_numbers.unwrap_or_default();
         let parsed_file = match ParsedFilePath::from_args(&args.file, line_numbers) {
             Ok(parsed) => parsed,
@@ -287,18 +1142,75 @@ impl TestRunner {
         };
         cmd.arg(&rspec_arg);
 
+        // Request the documentation JSON formatter so failures come back as
+        // structured data rather than free-form console text.
+        cmd.arg("--format");
+        cmd.arg("json");
+        // Pass the seed through so RSpec orders examples reproducibly too.
+        cmd.arg("--seed");
+        cmd.arg(effective_seed.to_string());
+
         match cmd.output().await {
             Ok(output) => {
                 let stdout = String::from_utf8_lossy(&output.stdout);
                 let stderr = String::from_utf8_lossy(&output.stderr);
                 let status = output.status.code().unwrap_or(-1);
 
-                let result_text = format!(
-                    "Test Results for: {}\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
-                    rspec_arg, status, stdout, stderr
-                );
-
-                Ok(CallToolResult::success(vec![Content::text(result_text)]))
+                // Try to extract and parse JSON from RSpec output
+                match Self::extract_json_from_cypress_output(&stdout) {
+                    Ok(json_str) => match Self::parse_rspec_results(&json_str) {
+                        Ok(results) => {
+                            let mut normalized = Self::normalize_rspec_results(&results);
+                            normalized.git = crate::git::collect(".").await;
+                            let filtered_results = Self::filter_rspec_results(results);
+
+                            match serde_json::to_string_pretty(&filtered_results) {
+                                Ok(clean_json) => {
+                                    let mut result_text = format!(
+                                        "Test Results for: {}\nExit Code: {}\nSeed: {}",
+                                        rspec_arg, status, effective_seed
+                                    );
+                                    if let Some(dir) = args.output_dir.as_deref() {
+                                        match Self::persist_artifacts(
+                                            dir, "rspec", &stdout, &stderr, &clean_json,
+                                        ) {
+                                            Ok(paths) => result_text
+                                                .push_str(&format!("\nArtifacts:\n{}", paths.join("\n"))),
+                                            Err(e) => result_text
+                                                .push_str(&format!("\nArtifact persistence failed: {}", e)),
+                                        }
+                                    }
+                                    if !args.skip_body.unwrap_or(false) {
+                                        result_text
+                                            .push_str(&format!("\n\nFiltered Results:\n{}", clean_json));
+                                    }
+                                    Ok(Self::success_with_structured(result_text, &normalized))
+                                }
+                                Err(e) => {
+                                    let result_text = format!(
+                                        "Test Results for: {} (JSON serialization failed: {})\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
+                                        rspec_arg, e, status, stdout, stderr
+                                    );
+                                    Ok(CallToolResult::success(vec![Content::text(result_text)]))
+                                }
+                            }
+                        }
+                        Err(parse_error) => {
+                            let result_text = format!(
+                                "Test Results for: {} (JSON parsing failed: {})\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
+                                rspec_arg, parse_error, status, stdout, stderr
+                            );
+                            Ok(CallToolResult::success(vec![Content::text(result_text)]))
+                        }
+                    },
+                    Err(extract_error) => {
+                        let result_text = format!(
+                            "Test Results for: {} (JSON extraction failed: {})\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
+                            rspec_arg, extract_error, status, stdout, stderr
+                        );
+                        Ok(CallToolResult::success(vec![Content::text(result_text)]))
+                    }
+                }
             }
             Err(e) => Err(McpError::internal_error(
                 format!("Command failed: {}", e),
@@ -307,15 +1219,39 @@ impl TestRunner {
         }
     }
 
+    /// Execute the configured RSpec command for a single target and return the
+    /// normalized results, if the run produced parseable JSON. Used by watch
+    /// mode to emit incremental summaries without rebuilding the tool plumbing.
+    async fn rspec_once(&self, rspec_arg: &str, seed: u64) -> Option<NormalizedResults> {
+        let command_parts: Vec<&str> = self.rspec_command.split_whitespace().collect();
+        let mut cmd = Command::new(command_parts[0]);
+        for part in &command_parts[1..] {
+            cmd.arg(part);
+        }
+        cmd.arg(rspec_arg);
+        cmd.arg("--format");
+        cmd.arg("json");
+        cmd.arg("--seed");
+        cmd.arg(seed.to_string());
+
+        let output = cmd.output().await.ok()?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json_str = Self::extract_json_from_cypress_output(&stdout).ok()?;
+        let results = Self::parse_rspec_results(&json_str).ok()?;
+        Some(Self::normalize_rspec_results(&results))
+    }
+
     #[tool(
-        description = "Run Cypress tests for a specific file. Accepts file paths ending in '.cy.js' or '.cy.ts'"
+        description = "Watch an RSpec file and re-run it on every change, emitting an incremental summary per run. Runs until the server shuts down"
     )]
-    async fn run_cypress(
+    async fn watch_rspec(
         &self,
-        Parameters(args): Parameters<CypressArgs>,
+        Parameters(args): Parameters<TestRunnerArgs>,
+        context: RequestContext<RoleServer>,
     ) -> Result<CallToolResult, McpError> {
-        // Parse the file path and validate format
-        let parsed_file = match ParsedFilePath::from_cypress_args(&args.file) {
+        let effective_seed = args.seed.unwrap_or_else(random_seed);
+        let line_numbers = args.line_numbers.unwrap_or_default();
+        let parsed_file = match ParsedFilePath::from_args(&args.file, line_numbers) {
             Ok(parsed) => parsed,
             Err(e) => {
                 return Err(McpError::invalid_params(
@@ -325,9 +1261,111 @@ impl TestRunner {
             }
         };
 
-        let mut cmd = Command::new("sh");
-        cmd.arg("-c");
-        cmd.arg(format!("{} {}", self.cypress_command, parsed_file.file_path));
+        let rspec_arg = if parsed_file.line_numbers.is_empty() {
+            parsed_file.file_path.clone()
+        } else {
+            format!(
+                "{}:{}",
+                parsed_file.file_path,
+                parsed_file
+                    .line_numbers
+                    .iter()
+                    .map(|n| n.to_string())
+                    .collect::<Vec<_>>()
+                    .join(":")
+            )
+        };
+        let watch_path = parsed_file.file_path.clone();
+
+        // Drive the watch loop in the background so the initial result returns
+        // promptly; each subsequent change pushes an incremental summary to the
+        // client as a logging notification over the open MCP connection.
+        let runner = self.clone();
+        let peer = context.peer.clone();
+        tokio::spawn(async move {
+            let result = crate::watcher::watch_and_rerun(&watch_path, || {
+                let runner = runner.clone();
+                let rspec_arg = rspec_arg.clone();
+                let peer = peer.clone();
+                async move {
+                    match runner.rspec_once(&rspec_arg, effective_seed).await {
+                        Some(n) => {
+                            tracing::info!(
+                                file = %rspec_arg,
+                                total = n.summary.total,
+                                failed = n.summary.failed,
+                                "watch re-run completed"
+                            );
+                            if let Ok(data) = serde_json::to_value(&n) {
+                                let _ = peer
+                                    .notify_logging_message(LoggingMessageNotificationParam {
+                                        level: LoggingLevel::Info,
+                                        logger: Some("watch_rspec".to_string()),
+                                        data,
+                                    })
+                                    .await;
+                            }
+                        }
+                        None => tracing::warn!(file = %rspec_arg, "watch re-run produced no parseable results"),
+                    }
+                }
+            })
+            .await;
+            if let Err(e) = result {
+                tracing::error!(error = %e, "watch loop terminated");
+            }
+        });
+
+        let result_text = format!(
+            "Watching: {} (seed: {})\nRe-running on every change; incremental summaries are emitted as the file is edited.",
+            parsed_file.file_path, effective_seed
+        );
+        Ok(CallToolResult::success(vec![Content::text(result_text)]))
+    }
+
+    #[tool(
+        description = "Run RSpec across a directory or glob, collecting every '*_spec.rb' file. Supports optional include/exclude glob lists and reports an aggregated summary"
+    )]
+    async fn run_rspec_suite(
+        &self,
+        Parameters(args): Parameters<RspecSuiteArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        let include = args.include.unwrap_or_default();
+        let exclude = args.exclude.unwrap_or_default();
+
+        let effective_seed = args.seed.unwrap_or_else(random_seed);
+        let files = match collect_spec_files(&args.path, &include, &exclude) {
+            Ok(files) if files.is_empty() => {
+                return Err(McpError::invalid_params(
+                    format!("No '*_spec.rb' files found under: {}", args.path),
+                    None,
+                ));
+            }
+            Ok(mut files) => {
+                // Shuffle file ordering for reproducible flaky-order detection.
+                SeededRng::new(effective_seed).shuffle(&mut files);
+                files
+            }
+            Err(e) => {
+                return Err(McpError::invalid_params(
+                    format!("Invalid parameters: {}", e),
+                    None,
+                ));
+            }
+        };
+
+        let command_parts: Vec<&str> = self.rspec_command.split_whitespace().collect();
+        let mut cmd = Command::new(command_parts[0]);
+        for part in &command_parts[1..] {
+            cmd.arg(part);
+        }
+        for file in &files {
+            cmd.arg(file);
+        }
+        cmd.arg("--format");
+        cmd.arg("json");
+        cmd.arg("--seed");
+        cmd.arg(effective_seed.to_string());
 
         match cmd.output().await {
             Ok(output) => {
@@ -335,52 +1373,279 @@ impl TestRunner {
                 let stderr = String::from_utf8_lossy(&output.stderr);
                 let status = output.status.code().unwrap_or(-1);
 
-                // Try to extract and parse JSON from Cypress output
                 match Self::extract_json_from_cypress_output(&stdout) {
-                    Ok(json_str) => {
-                        match Self::parse_cypress_results(&json_str) {
-                            Ok(results) => {
-                                // Filter out noise and return clean JSON
-                                let filtered_results = Self::filter_cypress_results(results);
-                                
-                                match serde_json::to_string_pretty(&filtered_results) {
-                                    Ok(clean_json) => {
-                                        let result_text = format!(
-                                            "Test Results for: {}\nExit Code: {}\n\nFiltered Results:\n{}",
-                                            parsed_file.file_path, status, clean_json
-                                        );
-                                        Ok(CallToolResult::success(vec![Content::text(result_text)]))
-                                    }
-                                    Err(e) => {
-                                        // Fallback to original output if JSON serialization fails
-                                        let result_text = format!(
-                                            "Test Results for: {} (JSON serialization failed: {})\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
-                                            parsed_file.file_path, e, status, stdout, stderr
-                                        );
-                                        Ok(CallToolResult::success(vec![Content::text(result_text)]))
-                                    }
+                    Ok(json_str) => match Self::parse_rspec_results(&json_str) {
+                        Ok(results) => {
+                            let mut normalized = Self::normalize_rspec_results(&results);
+                            normalized.git = crate::git::collect(".").await;
+                            let filtered_results = Self::filter_rspec_results(results);
+
+                            match serde_json::to_string_pretty(&filtered_results) {
+                                Ok(clean_json) => {
+                                    let result_text = format!(
+                                        "Suite Results for: {} ({} files)\nExit Code: {}\nSeed: {}\n\nFiltered Results:\n{}",
+                                        args.path, files.len(), status, effective_seed, clean_json
+                                    );
+                                    Ok(Self::success_with_structured(result_text, &normalized))
+                                }
+                                Err(e) => {
+                                    let result_text = format!(
+                                        "Suite Results for: {} (JSON serialization failed: {})\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
+                                        args.path, e, status, stdout, stderr
+                                    );
+                                    Ok(CallToolResult::success(vec![Content::text(result_text)]))
                                 }
                             }
-                            Err(parse_error) => {
-                                // Fallback to original output if JSON parsing fails
-                                let result_text = format!(
-                                    "Test Results for: {} (JSON parsing failed: {})\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
-                                    parsed_file.file_path, parse_error, status, stdout, stderr
-                                );
-                                Ok(CallToolResult::success(vec![Content::text(result_text)]))
+                        }
+                        Err(parse_error) => {
+                            let result_text = format!(
+                                "Suite Results for: {} (JSON parsing failed: {})\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
+                                args.path, parse_error, status, stdout, stderr
+                            );
+                            Ok(CallToolResult::success(vec![Content::text(result_text)]))
+                        }
+                    },
+                    Err(extract_error) => {
+                        let result_text = format!(
+                            "Suite Results for: {} (JSON extraction failed: {})\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
+                            args.path, extract_error, status, stdout, stderr
+                        );
+                        Ok(CallToolResult::success(vec![Content::text(result_text)]))
+                    }
+                }
+            }
+            Err(e) => Err(McpError::internal_error(
+                format!("Command failed: {}", e),
+                None,
+            )),
+        }
+    }
+
+    #[tool(
+        description = "Run Cypress tests for a specific file. Accepts file paths ending in '.cy.js' or '.cy.ts'; with a non-Cypress 'framework', any spec path passing the path-safety checks is accepted"
+    )]
+    async fn run_cypress(
+        &self,
+        Parameters(args): Parameters<CypressArgs>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        // When a non-Cypress framework is requested, validate with the relaxed
+        // (framework-neutral) rules, run the framework-appropriate command, and
+        // parse its output through the parser subsystem; only the Cypress path
+        // uses the `.cy.*` validation and streams incremental events.
+        if let Some(framework) = args.framework.as_deref() {
+            if !framework.eq_ignore_ascii_case("cypress") {
+                let parsed_file = match ParsedFilePath::from_framework_args(&args.file) {
+                    Ok(parsed) => parsed,
+                    Err(e) => {
+                        return Err(McpError::invalid_params(
+                            format!("Invalid parameters: {}", e),
+                            None,
+                        ));
+                    }
+                };
+                return self
+                    .run_other_framework(&parsed_file.file_path, framework)
+                    .await;
+            }
+        }
+
+        // Parse the file path and validate Cypress format
+        let parsed_file = match ParsedFilePath::from_cypress_args(&args.file) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                return Err(McpError::invalid_params(
+                    format!("Invalid parameters: {}", e),
+                    None,
+                ));
+            }
+        };
+
+        // Stream the Cypress run: spawn a forwarder that relays each incremental
+        // TestEvent to the client as a logging notification over the open MCP
+        // connection, while the authoritative results are assembled from the
+        // buffered stdout.
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<crate::events::TestEvent>(64);
+        let peer = context.peer.clone();
+        let forwarder = tokio::spawn(async move {
+            while let Some(event) = rx.recv().await {
+                if let Ok(data) = serde_json::to_value(&event) {
+                    let _ = peer
+                        .notify_logging_message(LoggingMessageNotificationParam {
+                            level: LoggingLevel::Info,
+                            logger: Some("run_cypress".to_string()),
+                            data,
+                        })
+                        .await;
+                }
+            }
+        });
+
+        crate::metrics::METRICS.inc_running();
+        let streamed =
+            crate::events::stream_cypress(&self.cypress_command, &parsed_file.file_path, tx).await;
+        crate::metrics::METRICS.dec_running();
+        let _ = forwarder.await;
+
+        let run = match streamed {
+            Ok(run) => run,
+            Err(e) => {
+                return Err(McpError::internal_error(
+                    format!("Command failed: {}", e),
+                    None,
+                ));
+            }
+        };
+        let stdout = run.stdout;
+        let stderr = run.stderr;
+        let status = run.exit_code;
+
+        // Extract and parse JSON from the buffered Cypress output, keeping the
+        // same raw stdout/stderr fallback the non-streaming path had. When the
+        // reporter emitted several top-level objects (parallel shards / NDJSON)
+        // they are merged into one consolidated tree; a trailing unbalanced
+        // brace falls back to the tolerant first-object extractor.
+        let parsed = match Self::extract_all_json_objects(&stdout) {
+            Ok(objects) if objects.len() > 1 => {
+                let refs: Vec<&str> = objects.iter().map(String::as_str).collect();
+                Self::parse_and_merge(&refs)
+            }
+            Ok(objects) if objects.len() == 1 => Self::parse_cypress_results(&objects[0]),
+            _ => Self::extract_json_from_cypress_output(&stdout)
+                .and_then(|json| Self::parse_cypress_results(&json)),
+        };
+
+        match parsed {
+            Ok(results) => {
+                // Filter out noise and return clean JSON
+                let mut normalized = Self::normalize_cypress_results(&results);
+                normalized.git = crate::git::collect(".").await;
+                let filtered_results = Self::filter_cypress_results(results);
+
+                // Update Prometheus counters/histogram.
+                crate::metrics::METRICS.record_run(
+                    filtered_results.stats.passes,
+                    filtered_results.stats.failures,
+                    filtered_results.stats.pending,
+                    filtered_results.stats.duration,
+                );
+
+                // Best-effort webhook notification with the run summary.
+                if let Some(config) = self.webhook.clone() {
+                    let summary = Self::normalize_cypress_results(&filtered_results);
+                    let failing: Vec<String> = filtered_results
+                        .failures
+                        .iter()
+                        .map(|t| t.full_title.clone())
+                        .collect();
+                    let start = filtered_results.stats.start.clone();
+                    let end = filtered_results.stats.end.clone();
+                    tokio::spawn(async move {
+                        crate::webhook::post_summary(&config, &summary, &failing, &start, &end)
+                            .await;
+                    });
+                }
+
+                let body = match args.format.as_deref() {
+                    Some("sonar") => Ok(crate::sonar::to_sonar_xml(
+                        &filtered_results,
+                        args.sonar_path_prefix.as_deref().unwrap_or(""),
+                    )),
+                    _ => serde_json::to_string_pretty(&filtered_results),
+                };
+                match body {
+                    Ok(clean_json) => {
+                        let mut result_text = format!(
+                            "Test Results for: {}\nExit Code: {}",
+                            parsed_file.file_path, status
+                        );
+                        if let Some(dir) = args.output_dir.as_deref() {
+                            match Self::persist_artifacts(
+                                dir, "cypress", &stdout, &stderr, &clean_json,
+                            ) {
+                                Ok(paths) => result_text
+                                    .push_str(&format!("\nArtifacts:\n{}", paths.join("\n"))),
+                                Err(e) => result_text
+                                    .push_str(&format!("\nArtifact persistence failed: {}", e)),
                             }
                         }
+                        if !args.skip_body.unwrap_or(false) {
+                            result_text
+                                .push_str(&format!("\n\nFiltered Results:\n{}", clean_json));
+                        }
+                        Ok(Self::success_with_structured(result_text, &normalized))
                     }
-                    Err(extract_error) => {
-                        // Fallback to original output if JSON extraction fails
+                    Err(e) => {
+                        // Fallback to original output if JSON serialization fails
                         let result_text = format!(
-                            "Test Results for: {} (JSON extraction failed: {})\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
-                            parsed_file.file_path, extract_error, status, stdout, stderr
+                            "Test Results for: {} (JSON serialization failed: {})\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
+                            parsed_file.file_path, e, status, stdout, stderr
                         );
                         Ok(CallToolResult::success(vec![Content::text(result_text)]))
                     }
                 }
             }
+            Err(parse_error) => {
+                // Fallback to original output if the JSON could not be parsed.
+                let result_text = format!(
+                    "Test Results for: {} (JSON parsing failed: {})\nExit Code: {}\n\nOutput:\n{}\n\nErrors:\n{}",
+                    parsed_file.file_path, parse_error, status, stdout, stderr
+                );
+                Ok(CallToolResult::success(vec![Content::text(result_text)]))
+            }
+        }
+    }
+
+    /// Default shell command for a non-Cypress framework, chosen so the runner
+    /// emits the machine-readable JSON report its parser expects on stdout. The
+    /// spec path is appended by the caller. Unknown frameworks fall back to the
+    /// configured Cypress command (matching `parser_for`'s Cypress default).
+    fn framework_command(&self, framework: &str) -> String {
+        match framework.to_lowercase().as_str() {
+            "jest" => "npx jest --json".to_string(),
+            "vitest" => "npx vitest run --reporter=json".to_string(),
+            "mocha" => "npx mocha --reporter json".to_string(),
+            "playwright" => "npx playwright test --reporter=json".to_string(),
+            _ => self.cypress_command.clone(),
+        }
+    }
+
+    /// Run a single spec under a non-Cypress framework, parse its raw output
+    /// through the framework-neutral parser subsystem, and return the
+    /// normalized report. Unlike the Cypress path this does not stream.
+    async fn run_other_framework(
+        &self,
+        file_path: &str,
+        framework: &str,
+    ) -> Result<CallToolResult, McpError> {
+        let command = self.framework_command(framework);
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd.arg(format!("{} {}", command, file_path));
+
+        crate::metrics::METRICS.inc_running();
+        let output = cmd.output().await;
+        crate::metrics::METRICS.dec_running();
+
+        match output {
+            Ok(output) => {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let status = output.status.code().unwrap_or(-1);
+                Ok(match crate::parsers::parser_for(framework).parse(&stdout) {
+                    Ok(report) => {
+                        let body = serde_json::to_string_pretty(&report)
+                            .unwrap_or_else(|e| format!("(serialization failed: {})", e));
+                        CallToolResult::success(vec![Content::text(format!(
+                            "Test Results for: {} ({})\nExit Code: {}\n\n{}",
+                            file_path, framework, status, body
+                        ))])
+                    }
+                    Err(e) => CallToolResult::success(vec![Content::text(format!(
+                        "Test Results for: {} ({} parse failed: {})\nExit Code: {}\n\nOutput:\n{}",
+                        file_path, framework, e, status, stdout
+                    ))]),
+                })
+            }
             Err(e) => Err(McpError::internal_error(
                 format!("Command failed: {}", e),
                 None,
@@ -399,7 +1664,7 @@ impl ServerHandler for TestRunner {
                 .build(),
             server_info: Implementation::from_build_env(),
             instructions: Some(
-                "Test runner server using configurable commands. Tools: run_rspec (run RSpec tests), run_cypress (run Cypress tests)."
+                "Test runner server using configurable commands. Tools: run_rspec (run RSpec tests), run_rspec_suite (run a directory/glob of specs), watch_rspec (re-run an RSpec file on change), run_cypress (run Cypress tests)."
                     .to_string(),
             ),
         }
@@ -428,13 +1693,55 @@ mod tests {
         let router = TestRunner::new("bundle exec rspec".to_string(), "npx cypress run --spec".to_string()).tool_router;
 
         let tools = router.list_all();
-        assert_eq!(tools.len(), 2);
+        assert_eq!(tools.len(), 4);
 
         let tool_names: Vec<&str> = tools.iter().map(|t| t.name.as_ref()).collect();
         assert!(tool_names.contains(&"run_rspec"));
+        assert!(tool_names.contains(&"run_rspec_suite"));
+        assert!(tool_names.contains(&"watch_rspec"));
         assert!(tool_names.contains(&"run_cypress"));
     }
 
+    #[test]
+    fn test_seeded_shuffle_is_deterministic() {
+        let mut a: Vec<u32> = (0..10).collect();
+        let mut b: Vec<u32> = (0..10).collect();
+        SeededRng::new(42).shuffle(&mut a);
+        SeededRng::new(42).shuffle(&mut b);
+        assert_eq!(a, b);
+
+        let mut c: Vec<u32> = (0..10).collect();
+        SeededRng::new(43).shuffle(&mut c);
+        assert_ne!(a, c);
+
+        // A shuffle is a permutation: the multiset of elements is preserved.
+        let mut sorted = a.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("spec/models/**", "spec/models/user_spec.rb"));
+        assert!(glob_match("spec/models/**", "spec/models/admin/role_spec.rb"));
+        assert!(!glob_match("spec/models/**", "spec/requests/user_spec.rb"));
+        assert!(glob_match("*_spec.rb", "user_spec.rb"));
+        assert!(!glob_match("spec/*.rb", "spec/models/user_spec.rb"));
+    }
+
+    #[test]
+    fn test_split_glob_root() {
+        assert_eq!(
+            split_glob_root("spec/models/**/*_spec.rb"),
+            ("spec/models".to_string(), Some("spec/models/**/*_spec.rb".to_string()))
+        );
+        assert_eq!(
+            split_glob_root("*_spec.rb"),
+            (".".to_string(), Some("*_spec.rb".to_string()))
+        );
+        assert_eq!(split_glob_root("spec/models"), ("spec/models".to_string(), None));
+    }
+
     #[test]
     fn test_test_runner_args_deserialization() {
         let json = r#"
@@ -586,6 +1893,17 @@ mod tests {
         assert!(parsed.line_numbers.is_empty());
     }
 
+    #[test]
+    fn test_from_framework_args_allows_non_cypress_specs() {
+        // Jest/Playwright specs are rejected by the Cypress validator but must
+        // pass the relaxed framework validator.
+        assert!(ParsedFilePath::from_cypress_args("src/sum.test.js").is_err());
+        let parsed = ParsedFilePath::from_framework_args("src/sum.test.js").unwrap();
+        assert_eq!(parsed.file_path, "src/sum.test.js");
+        // Safety checks still apply.
+        assert!(ParsedFilePath::from_framework_args("../etc/passwd.test.js").is_err());
+    }
+
     #[test]
     fn test_from_cypress_args_with_optional_prefix() {
         let parsed = ParsedFilePath::from_cypress_args("./cypress/e2e/user-login.cy.js").unwrap();
@@ -684,6 +2002,40 @@ mod tests {
         assert_eq!(result.unwrap_err(), "No JSON found in Cypress output");
     }
 
+    #[test]
+    fn test_extract_json_with_trailing_logs() {
+        let output = "warning\n{\"a\": \"}{\", \"b\": 1}\n[dbus] trailing log line";
+        let result = TestRunner::extract_json_from_cypress_output(output).unwrap();
+        // Braces inside the string literal must not truncate the object, and the
+        // trailing log line must be excluded.
+        assert_eq!(result, r#"{"a": "}{", "b": 1}"#);
+    }
+
+    #[test]
+    fn test_extract_json_ignores_unbalanced_trailing_brace() {
+        // A real object followed by a log line with a stray '{' must still
+        // yield the first object — scanning stops once it closes.
+        let output = "{\"a\": 1}\n[electron] spawn failed at process {pid\n";
+        let result = TestRunner::extract_json_from_cypress_output(output).unwrap();
+        assert_eq!(result, r#"{"a": 1}"#);
+    }
+
+    #[test]
+    fn test_extract_all_json_objects() {
+        let output = "{\"x\":1}\n{\"y\":2}\n";
+        let objects = TestRunner::extract_all_json_objects(output).unwrap();
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects[0], r#"{"x":1}"#);
+        assert_eq!(objects[1], r#"{"y":2}"#);
+    }
+
+    #[test]
+    fn test_extract_json_unterminated() {
+        let result = TestRunner::extract_all_json_objects("noise {\"a\": 1");
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), "Unterminated JSON object in output");
+    }
+
     #[test]
     fn test_parse_cypress_results() {
         let json_str = r#"{
@@ -734,4 +2086,176 @@ mod tests {
         assert_eq!(parsed.tests[0].title, "Test title");
         assert!(parsed.tests[0].err.is_some());
     }
+
+    #[test]
+    fn test_parse_rspec_results() {
+        let json_str = r#"{
+            "examples": [
+                {
+                    "description": "is valid",
+                    "full_description": "User is valid",
+                    "file_path": "./spec/models/user_spec.rb",
+                    "line_number": 37,
+                    "status": "failed",
+                    "exception": {
+                        "class": "RSpec::Expectations::ExpectationNotMetError",
+                        "message": "expected true, got false",
+                        "backtrace": ["./spec/models/user_spec.rb:39:in `block'"]
+                    }
+                }
+            ],
+            "summary": {
+                "duration": 0.12,
+                "example_count": 1,
+                "failure_count": 1,
+                "pending_count": 0
+            }
+        }"#;
+
+        let result = TestRunner::parse_rspec_results(json_str);
+        assert!(result.is_ok());
+
+        let parsed = result.unwrap();
+        assert_eq!(parsed.summary.example_count, 1);
+        assert_eq!(parsed.summary.failure_count, 1);
+        assert_eq!(parsed.examples.len(), 1);
+        assert_eq!(parsed.examples[0].line_number, 37);
+        assert!(parsed.examples[0].exception.is_some());
+    }
+
+    fn make_test(attempts: &[&str], err: bool) -> CypressTest {
+        CypressTest {
+            title: "t".to_string(),
+            full_title: "full t".to_string(),
+            file: None,
+            duration: None,
+            current_retry: 0,
+            err: if err {
+                Some(CypressError {
+                    message: "boom".to_string(),
+                    name: "AssertionError".to_string(),
+                    code_frame: None,
+                    stack: None,
+                    frames: vec![],
+                })
+            } else {
+                None
+            },
+            attempts: attempts
+                .iter()
+                .map(|s| Attempt {
+                    state: s.to_string(),
+                    duration: None,
+                    err: None,
+                })
+                .collect(),
+            classification: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_and_merge() {
+        let shard = |start: &str, end: &str, title: &str| {
+            format!(
+                r#"{{
+                    "stats": {{ "suites": 1, "tests": 1, "passes": 1, "pending": 0, "failures": 0, "start": "{start}", "end": "{end}", "duration": 100 }},
+                    "tests": [{{ "title": "{title}", "fullTitle": "{title}", "file": "a.cy.js", "duration": 1, "currentRetry": 0, "err": null }}],
+                    "pending": [], "failures": [],
+                    "passes": [{{ "title": "{title}", "fullTitle": "{title}", "file": "a.cy.js", "duration": 1, "currentRetry": 0, "err": null }}]
+                }}"#
+            )
+        };
+
+        let a = shard("2025-01-01T10:00:00Z", "2025-01-01T10:01:00Z", "one");
+        let b = shard("2025-01-01T10:00:30Z", "2025-01-01T10:02:00Z", "two");
+        let c = shard("2025-01-01T09:59:00Z", "2025-01-01T10:00:45Z", "two"); // duplicate of b
+
+        let merged = TestRunner::parse_and_merge(&[&a, &b, &c]).unwrap();
+        // Stats are recomputed from the de-duplicated arrays, so the duplicate
+        // "two" shard is counted once and stats stay consistent with the cases.
+        assert_eq!(merged.stats.tests, 2);
+        assert_eq!(merged.stats.passes, 2);
+        assert_eq!(merged.stats.start, "2025-01-01T09:59:00Z");
+        assert_eq!(merged.stats.end, "2025-01-01T10:02:00Z");
+        // "two" appears in two shards but de-dupes to a single entry.
+        assert_eq!(merged.tests.len(), 2);
+        assert_eq!(merged.passes.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_stack() {
+        let stack = "AssertionError: expected true to be false\n    at Context.eval (webpack:///./cypress/e2e/login.cy.js:23:47)\n    at getRet (https://example.com/bundle.js:1:1)\n    at runnable (node_modules/cypress/lib/runner.js:10:5)\n    at ./cypress/e2e/login.cy.js:30:3";
+
+        let frames = parse_stack(stack);
+        assert_eq!(frames.len(), 3);
+
+        assert_eq!(frames[0].function.as_deref(), Some("Context.eval"));
+        assert_eq!(frames[0].line, 23);
+        assert_eq!(frames[0].column, 47);
+        assert!(frames[0].navigable);
+
+        // node_modules frame is kept but flagged non-navigable.
+        assert!(!frames[1].navigable);
+
+        // Bare `at file:line:col` form with no function.
+        assert_eq!(frames[2].function, None);
+        assert_eq!(frames[2].line, 30);
+    }
+
+    #[test]
+    fn test_classify_flaky() {
+        assert_eq!(
+            make_test(&["failed", "passed"], false).classify(),
+            Classification::Flaky
+        );
+        assert_eq!(
+            make_test(&["passed"], false).classify(),
+            Classification::Passed
+        );
+        assert_eq!(
+            make_test(&["failed", "failed"], true).classify(),
+            Classification::Failed
+        );
+        // A before-all hook abort leaves a single failing attempt plus skipped
+        // placeholders — this is a failure, not flakiness.
+        assert_eq!(
+            make_test(&["failed", "skipped"], true).classify(),
+            Classification::Failed
+        );
+        // No attempts recorded: fall back to err presence.
+        assert_eq!(make_test(&[], true).classify(), Classification::Failed);
+        assert_eq!(make_test(&[], false).classify(), Classification::Passed);
+    }
+
+    #[test]
+    fn test_normalize_rspec_results() {
+        let results = RspecResults {
+            examples: vec![RspecExample {
+                description: "is valid".to_string(),
+                full_description: "User is valid".to_string(),
+                file_path: "./spec/models/user_spec.rb".to_string(),
+                line_number: 37,
+                status: "failed".to_string(),
+                exception: Some(RspecException {
+                    class: "RSpec::Expectations::ExpectationNotMetError".to_string(),
+                    message: "expected true, got false".to_string(),
+                    backtrace: Some(vec!["./spec/models/user_spec.rb:39".to_string()]),
+                }),
+            }],
+            summary: RspecSummary {
+                duration: 0.12,
+                example_count: 3,
+                failure_count: 1,
+                pending_count: 1,
+            },
+        };
+
+        let normalized = TestRunner::normalize_rspec_results(&results);
+        assert_eq!(normalized.summary.total, 3);
+        assert_eq!(normalized.summary.failed, 1);
+        assert_eq!(normalized.summary.pending, 1);
+        assert_eq!(normalized.summary.passed, 1);
+        assert_eq!(normalized.cases.len(), 1);
+        assert_eq!(normalized.cases[0].status, TestStatus::Failed);
+    }
 }
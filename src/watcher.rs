@@ -0,0 +1,57 @@
+use std::path::Path;
+use std::time::Duration;
+
+use notify::{Event, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+
+/// Debounce window for coalescing bursts of filesystem events (editors often
+/// emit several writes per save).
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watch `path`, invoke `on_change` once up front, then re-run it on every
+/// debounced filesystem change. The watcher lives inside this future, so the
+/// loop keeps running until the future (or the task driving it) is dropped.
+///
+/// `path` may be a single spec file or a directory (suite mode); directories
+/// are watched recursively. Each invocation of `on_change` is expected to
+/// produce an incremental structured result that the caller forwards to its
+/// client, keeping a test-feedback loop open while code is edited.
+pub async fn watch_and_rerun<F, Fut>(path: &str, mut on_change: F) -> Result<(), String>
+where
+    F: FnMut() -> Fut + Send,
+    Fut: std::future::Future<Output = ()> + Send,
+{
+    let target = Path::new(path);
+    let recursive = if target.is_dir() {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+
+    // notify runs its own OS thread and invokes this callback there; forward
+    // each event onto a tokio channel so the async re-run loop can await them.
+    let (tx, mut rx) = mpsc::unbounded_channel::<()>();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        if res.is_ok() {
+            let _ = tx.send(());
+        }
+    })
+    .map_err(|e| format!("Failed to create watcher: {}", e))?;
+
+    watcher
+        .watch(target, recursive)
+        .map_err(|e| format!("Failed to watch {}: {}", path, e))?;
+
+    // Initial run before we start reacting to changes.
+    on_change().await;
+
+    while rx.recv().await.is_some() {
+        // Debounce: drain any events that arrive within the window so a single
+        // save triggers exactly one re-run.
+        tokio::time::sleep(DEBOUNCE).await;
+        while rx.try_recv().is_ok() {}
+        on_change().await;
+    }
+
+    Ok(())
+}
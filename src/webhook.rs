@@ -0,0 +1,85 @@
+use serde_json::json;
+
+use crate::test_runner::NormalizedResults;
+
+/// Configuration for posting run summaries to a chat/incoming-webhook endpoint.
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    /// Target URL of the incoming webhook (Slack/Mattermost-style).
+    pub url: String,
+    /// Optional author/label string shown on the message.
+    pub label: Option<String>,
+    /// When true, only post when the run contains failures.
+    pub on_failure_only: bool,
+}
+
+/// Build the attachment payload and POST it to the configured webhook.
+///
+/// Best-effort: any transport or serialization error is logged and swallowed so
+/// notification never aborts the MCP response.
+pub async fn post_summary(
+    config: &WebhookConfig,
+    summary: &NormalizedResults,
+    failing_specs: &[String],
+    start: &str,
+    end: &str,
+) {
+    if config.on_failure_only && summary.summary.failed == 0 {
+        return;
+    }
+
+    // Green when everything passed, red when anything failed.
+    let color = if summary.summary.failed > 0 {
+        "#d00000"
+    } else {
+        "#2eb886"
+    };
+
+    let text = format!(
+        "{} passed, {} failed, {} pending",
+        summary.summary.passed, summary.summary.failed, summary.summary.pending
+    );
+
+    let mut fields = vec![
+        json!({ "title": "Total", "value": summary.summary.total, "short": true }),
+        json!({ "title": "Failed", "value": summary.summary.failed, "short": true }),
+    ];
+    // The run span is reported as ISO-8601 strings, which Slack/Mattermost's
+    // numeric `ts` field would misrender — surface them as fields instead.
+    if !start.is_empty() {
+        fields.push(json!({ "title": "Started", "value": start, "short": false }));
+    }
+    if !end.is_empty() {
+        fields.push(json!({ "title": "Ended", "value": end, "short": false }));
+    }
+    if !failing_specs.is_empty() {
+        fields.push(json!({
+            "title": "Failing specs",
+            "value": failing_specs.join("\n"),
+            "short": false,
+        }));
+    }
+
+    let payload = json!({
+        "attachments": [{
+            "color": color,
+            "author_name": config.label.clone().unwrap_or_default(),
+            "title": "Test run summary",
+            "text": text,
+            "fields": fields,
+        }]
+    });
+
+    let client = reqwest::Client::new();
+    match client.post(&config.url).json(&payload).send().await {
+        Ok(resp) if resp.status().is_success() => {
+            tracing::info!(status = %resp.status(), "posted run summary to webhook");
+        }
+        Ok(resp) => {
+            tracing::warn!(status = %resp.status(), "webhook returned non-success status");
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "failed to post run summary to webhook");
+        }
+    }
+}